@@ -61,6 +61,7 @@ pub fn generate_operation(operation: &Operation) -> TokenStream {
         #[doc = "\n\n"]
         #[doc = #description]
         #[derive(Clone, Debug, PartialEq, Eq)]
+        #[repr(transparent)]
         pub struct #identifier<'c> {
             operation: ::melior::ir::operation::Operation<'c>,
         }
@@ -110,6 +111,34 @@ pub fn generate_operation(operation: &Operation) -> TokenStream {
             }
         }
 
+        impl<'c> ::melior::ir::Downcast<::melior::ir::operation::Operation<'c>> for #identifier<'c> {
+            fn isa(operation: &::melior::ir::operation::Operation<'c>) -> bool {
+                operation.name().as_string_ref().as_str() == Ok(#operation_name)
+            }
+
+            unsafe fn cast_unchecked(operation: ::melior::ir::operation::Operation<'c>) -> Self {
+                Self { operation }
+            }
+        }
+
+        impl<'c, 'a> ::melior::ir::Downcast<::melior::ir::operation::OperationRef<'c, 'a>>
+            for &'a #identifier<'c>
+        {
+            fn isa(operation: &::melior::ir::operation::OperationRef<'c, 'a>) -> bool {
+                <#identifier<'c> as ::melior::ir::Downcast<::melior::ir::operation::Operation<'c>>>::isa(
+                    operation,
+                )
+            }
+
+            unsafe fn cast_unchecked(operation: ::melior::ir::operation::OperationRef<'c, 'a>) -> Self {
+                // SAFETY: Typed operations are transparent wrappers of generic ones.
+                unsafe {
+                    &*(operation.to_ref() as *const ::melior::ir::operation::Operation<'c>
+                        as *const #identifier<'c>)
+                }
+            }
+        }
+
         impl<'c> From<#identifier<'c>> for ::melior::ir::operation::Operation<'c> {
             fn from(operation: #identifier<'c>) -> Self {
                 operation.operation
@@ -3,11 +3,12 @@ mod dialect;
 mod operation;
 mod parse;
 mod pass;
+mod rewrite;
 mod r#type;
 mod utility;
 
 use dialect::DialectInput;
-use parse::{DialectOperationSet, IdentifierList, PassSet};
+use parse::{DialectOperationSet, IdentifierList, PassSet, RewriteInput};
 use proc_macro::TokenStream;
 use quote::quote;
 use std::error::Error;
@@ -90,6 +91,30 @@ pub fn passes(stream: TokenStream) -> TokenStream {
     }))
 }
 
+/// Generates a rewrite pattern from source and result DAGs.
+///
+/// Operations are written as `(name {attribute = "value"} arguments...)`,
+/// variables bound to operands as `$name`, and integer or float literals as
+/// `value` attributes of operations like `arith.constant`. Literals can have
+/// types as `1 : i64` or `1 : "vector<4xi64>"`, which are required in nested
+/// operations of results. A benefit of a pattern can be given optionally.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let pattern = melior::rewrite!(
+///     &context,
+///     (arith.addi $x, (arith.constant 0)) => $x,
+///     benefit = 2,
+/// );
+/// ```
+#[proc_macro]
+pub fn rewrite(stream: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(stream as RewriteInput);
+
+    rewrite::generate(&input).unwrap_or_else(|error| error.to_compile_error().into())
+}
+
 fn convert_result(result: Result<TokenStream, Box<dyn Error>>) -> TokenStream {
     result.unwrap_or_else(|error| {
         let message = error.to_string();
@@ -1,7 +1,9 @@
 mod dialect_operation_set;
 mod identifier_list;
 mod pass_set;
+mod rewrite_input;
 
 pub use dialect_operation_set::DialectOperationSet;
 pub use identifier_list::IdentifierList;
 pub use pass_set::PassSet;
+pub use rewrite_input::{Dag, Literal, LiteralValue, OperationDag, RewriteInput};
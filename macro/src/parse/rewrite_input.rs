@@ -0,0 +1,207 @@
+use proc_macro2::Ident;
+use syn::{
+    Expr, LitFloat, LitInt, LitStr, Result, Token, braced,
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token,
+};
+
+pub struct RewriteInput {
+    context: Expr,
+    source: Dag,
+    result: Dag,
+    benefit: Option<LitInt>,
+}
+
+impl RewriteInput {
+    pub const fn context(&self) -> &Expr {
+        &self.context
+    }
+
+    pub const fn source(&self) -> &Dag {
+        &self.source
+    }
+
+    pub const fn result(&self) -> &Dag {
+        &self.result
+    }
+
+    pub const fn benefit(&self) -> Option<&LitInt> {
+        self.benefit.as_ref()
+    }
+}
+
+impl Parse for RewriteInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let context = input.parse()?;
+        <Token![,]>::parse(input)?;
+        let source = input.parse()?;
+        <Token![=>]>::parse(input)?;
+        let result = input.parse()?;
+        let mut benefit = None;
+
+        if input.peek(Token![,]) {
+            <Token![,]>::parse(input)?;
+
+            if !input.is_empty() {
+                let name = Ident::parse(input)?;
+
+                if name != "benefit" {
+                    return Err(syn::Error::new(name.span(), "expected `benefit`"));
+                }
+
+                <Token![=]>::parse(input)?;
+                benefit = Some(input.parse()?);
+                Option::<Token![,]>::parse(input)?;
+            }
+        }
+
+        Ok(Self {
+            context,
+            source,
+            result,
+            benefit,
+        })
+    }
+}
+
+pub enum Dag {
+    Literal(Literal),
+    Operation(OperationDag),
+    Variable(Ident),
+}
+
+impl Parse for Dag {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![$]) {
+            <Token![$]>::parse(input)?;
+
+            Ok(Self::Variable(Ident::parse_any(input)?))
+        } else if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+
+            Ok(Self::Operation(content.parse()?))
+        } else {
+            Ok(Self::Literal(input.parse()?))
+        }
+    }
+}
+
+pub struct OperationDag {
+    name: String,
+    attributes: Vec<(Ident, LitStr)>,
+    arguments: Vec<Dag>,
+}
+
+impl OperationDag {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn attributes(&self) -> &[(Ident, LitStr)] {
+        &self.attributes
+    }
+
+    pub fn arguments(&self) -> &[Dag] {
+        &self.arguments
+    }
+}
+
+impl Parse for OperationDag {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut name = Ident::parse_any(input)?.to_string();
+
+        while input.peek(Token![.]) {
+            <Token![.]>::parse(input)?;
+            name.push('.');
+            name.push_str(&Ident::parse_any(input)?.to_string());
+        }
+
+        Ok(Self {
+            name,
+            attributes: if input.peek(token::Brace) {
+                let content;
+                braced!(content in input);
+
+                Punctuated::<_, Token![,]>::parse_terminated_with(&content, |input| {
+                    let name = Ident::parse_any(input)?;
+                    <Token![=]>::parse(input)?;
+
+                    Ok((name, input.parse()?))
+                })?
+                .into_iter()
+                .collect()
+            } else {
+                vec![]
+            },
+            arguments: Punctuated::<Dag, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect(),
+        })
+    }
+}
+
+pub struct Literal {
+    value: LiteralValue,
+    r#type: Option<LitStr>,
+}
+
+impl Literal {
+    pub const fn value(&self) -> LiteralValue {
+        self.value
+    }
+
+    pub const fn r#type(&self) -> Option<&LitStr> {
+        self.r#type.as_ref()
+    }
+}
+
+impl Parse for Literal {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value = input.parse()?;
+        let r#type = if input.peek(Token![:]) {
+            <Token![:]>::parse(input)?;
+
+            Some(if input.peek(LitStr) {
+                input.parse()?
+            } else {
+                let name = Ident::parse_any(input)?;
+
+                LitStr::new(&name.to_string(), name.span())
+            })
+        } else {
+            None
+        };
+
+        Ok(Self { value, r#type })
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum LiteralValue {
+    Float(f64),
+    Integer(i64),
+}
+
+impl Parse for LiteralValue {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let negative = Option::<Token![-]>::parse(input)?.is_some();
+        let sign = if negative { -1 } else { 1 };
+        let lookahead = input.lookahead1();
+
+        if lookahead.peek(LitInt) {
+            Ok(Self::Integer(
+                sign * input.parse::<LitInt>()?.base10_parse::<i64>()?,
+            ))
+        } else if lookahead.peek(LitFloat) {
+            Ok(Self::Float(
+                sign as f64 * input.parse::<LitFloat>()?.base10_parse::<f64>()?,
+            ))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
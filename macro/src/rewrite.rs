@@ -0,0 +1,462 @@
+use crate::parse::{Dag, Literal, LiteralValue, OperationDag, RewriteInput};
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use std::collections::{HashMap, HashSet};
+use syn::{Error, LitStr};
+
+pub fn generate(input: &RewriteInput) -> Result<TokenStream, Error> {
+    let Dag::Operation(source) = input.source() else {
+        return Err(Error::new(
+            Span::call_site(),
+            "source of a rewrite must be an operation",
+        ));
+    };
+
+    let mut counts = HashMap::new();
+    count_variables(input.source(), &mut counts);
+
+    let mut generator = Generator {
+        counts,
+        result_variables: HashSet::new(),
+        bound_variables: HashSet::new(),
+        index: 0,
+        context_used: false,
+        type_used: false,
+        attributes: vec![],
+        types: vec![],
+        operations: vec![],
+    };
+
+    collect_variables(input.result(), &mut generator.result_variables);
+
+    for variable in &generator.result_variables {
+        if !generator.counts.contains_key(variable) {
+            return Err(Error::new(
+                Span::call_site(),
+                format!("unbound variable ${variable} in result of a rewrite"),
+            ));
+        }
+    }
+
+    let root = format_ident!("operation");
+    let matcher = generator.match_operation(source, &root)?;
+    let type_check = if let Dag::Variable(variable) = input.result() {
+        let variable = create_variable_identifier(variable);
+
+        quote! {
+            if operation.result(0).map(|result| result.r#type()).ok() != Some(#variable.r#type()) {
+                return false;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let mut names = vec![];
+    let (statements, value) = generator.build(input.result(), &mut names, true)?;
+
+    let context = input.context();
+    let attributes = generator
+        .attributes
+        .iter()
+        .map(|(identifier, value)| {
+            quote! {
+                let #identifier = melior::ir::AttributeLike::to_raw(
+                    &melior::ir::Attribute::parse(context, #value).expect("valid attribute"),
+                );
+            }
+        })
+        .collect::<Vec<_>>();
+    let types = generator
+        .types
+        .iter()
+        .map(|(identifier, value)| {
+            quote! {
+                let #identifier = melior::ir::TypeLike::to_raw(
+                    &melior::ir::Type::parse(context, #value).expect("valid type"),
+                );
+            }
+        })
+        .collect::<Vec<_>>();
+    let operations = &generator.operations;
+    let root_name = source.name();
+    let benefit = input
+        .benefit()
+        .map(|benefit| quote!(#benefit))
+        .unwrap_or_else(|| quote!(1));
+    let context_definition = if generator.context_used {
+        quote! { let context = unsafe { operation.context().to_ref() }; }
+    } else {
+        quote! {}
+    };
+    let type_definition = if generator.type_used {
+        quote! {
+            let Ok(r#type) = operation.result(0).map(|result| result.r#type()) else {
+                return false;
+            };
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {{
+        let context: &melior::Context = #context;
+
+        // Attributes and types are parsed once and captured as raw objects as
+        // they are uniqued in a context outliving the pattern.
+        #(#attributes)*
+        #(#types)*
+
+        melior::RewritePattern::new(
+            context,
+            #root_name,
+            #benefit,
+            &[#(#names),*],
+            move |operation: melior::ir::OperationRef, rewriter: &melior::PatternRewriter| -> bool {
+                #[allow(unused_imports)]
+                use melior::ir::{ValueLike, operation::OperationLike};
+
+                #context_definition
+
+                if operation.result_count() != 1 {
+                    return false;
+                }
+
+                #matcher
+                #type_check
+                #type_definition
+
+                // All operations are built before any is inserted so that a
+                // failure leaves the IR untouched.
+                #statements
+
+                let rewriter = rewriter.as_rewriter_base();
+                rewriter.set_insertion_point_before(operation);
+                #(rewriter.insert(#operations);)*
+                rewriter.replace_op_with_values(operation, &[#value]);
+
+                true
+            },
+        )
+    }}
+    .into())
+}
+
+struct Generator {
+    counts: HashMap<String, usize>,
+    result_variables: HashSet<String>,
+    bound_variables: HashSet<String>,
+    index: usize,
+    context_used: bool,
+    type_used: bool,
+    attributes: Vec<(Ident, LitStr)>,
+    types: Vec<(Ident, LitStr)>,
+    operations: Vec<Ident>,
+}
+
+impl Generator {
+    fn match_operation(
+        &mut self,
+        dag: &OperationDag,
+        operation: &Ident,
+    ) -> Result<TokenStream2, Error> {
+        let name = dag.name();
+        let operand_count = dag
+            .arguments()
+            .iter()
+            .filter(|argument| !matches!(argument, Dag::Literal(_)))
+            .count();
+        let mut stream = quote! {
+            if #operation.name().as_string_ref().as_str() != Ok(#name) {
+                return false;
+            }
+
+            if #operation.operand_count() != #operand_count {
+                return false;
+            }
+        };
+
+        for (name, value) in dag.attributes() {
+            let name = name.to_string();
+            let attribute = self.parse_attribute(value);
+
+            stream.extend(quote! {
+                if #operation.attribute(#name).ok()
+                    != Some(unsafe { melior::ir::Attribute::from_raw(#attribute) })
+                {
+                    return false;
+                }
+            });
+        }
+
+        let literal = find_literal(dag)?;
+
+        stream.extend(match literal.map(Literal::value) {
+            Some(LiteralValue::Integer(value)) => quote! {
+                if #operation
+                    .attribute("value")
+                    .ok()
+                    .and_then(|attribute| melior::ir::attribute::IntegerAttribute::try_from(attribute).ok())
+                    .map(|attribute| attribute.value())
+                    != Some(#value)
+                {
+                    return false;
+                }
+            },
+            Some(LiteralValue::Float(value)) => quote! {
+                if #operation
+                    .attribute("value")
+                    .ok()
+                    .and_then(|attribute| melior::ir::attribute::FloatAttribute::try_from(attribute).ok())
+                    .map(|attribute| attribute.value())
+                    != Some(#value)
+                {
+                    return false;
+                }
+            },
+            None => quote! {},
+        });
+
+        if let Some(r#type) = literal.and_then(Literal::r#type) {
+            let r#type = self.parse_type(r#type);
+
+            stream.extend(quote! {
+                if #operation
+                    .attribute("value")
+                    .ok()
+                    .map(|attribute| melior::ir::AttributeLike::r#type(&attribute))
+                    != Some(unsafe { melior::ir::Type::from_raw(#r#type) })
+                {
+                    return false;
+                }
+            });
+        }
+
+        for (index, argument) in dag
+            .arguments()
+            .iter()
+            .filter(|argument| !matches!(argument, Dag::Literal(_)))
+            .enumerate()
+        {
+            match argument {
+                Dag::Literal(_) => unreachable!(),
+                Dag::Operation(dag) => {
+                    let operand = self.create_identifier("operand");
+                    let nested = self.create_identifier("operation");
+
+                    stream.extend(quote! {
+                        let Ok(#operand) = #operation.operand(#index) else {
+                            return false;
+                        };
+                        let Ok(#nested) = melior::ir::operation::OperationResult::try_from(#operand)
+                            .map(|result| result.owner())
+                        else {
+                            return false;
+                        };
+                    });
+                    stream.extend(self.match_operation(dag, &nested)?);
+                }
+                Dag::Variable(name) => {
+                    let identifier = create_variable_identifier(name);
+                    let name = name.to_string();
+
+                    if self.bound_variables.insert(name.clone()) {
+                        if self.counts[&name] > 1 || self.result_variables.contains(&name) {
+                            stream.extend(quote! {
+                                let Ok(#identifier) = #operation.operand(#index) else {
+                                    return false;
+                                };
+                            });
+                        }
+                    } else {
+                        stream.extend(quote! {
+                            if #operation.operand(#index).ok() != Some(#identifier) {
+                                return false;
+                            }
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(stream)
+    }
+
+    fn build(
+        &mut self,
+        dag: &Dag,
+        names: &mut Vec<String>,
+        root: bool,
+    ) -> Result<(TokenStream2, TokenStream2), Error> {
+        match dag {
+            Dag::Literal(_) => Err(Error::new(
+                Span::call_site(),
+                "literals in results of a rewrite must be arguments of operations",
+            )),
+            Dag::Operation(dag) => {
+                let mut statements = quote! {};
+                let mut operands = vec![];
+                let mut attributes = vec![];
+
+                self.context_used = true;
+
+                for argument in dag.arguments() {
+                    if !matches!(argument, Dag::Literal(_)) {
+                        let (argument_statements, value) = self.build(argument, names, false)?;
+
+                        statements.extend(argument_statements);
+                        operands.push(value);
+                    }
+                }
+
+                if let Some(literal) = find_literal(dag)? {
+                    let r#type = if let Some(r#type) = literal.r#type() {
+                        let r#type = self.parse_type(r#type);
+
+                        quote!(unsafe { melior::ir::Type::from_raw(#r#type) })
+                    } else if root {
+                        self.type_used = true;
+
+                        quote!(r#type)
+                    } else {
+                        return Err(Error::new(
+                            Span::call_site(),
+                            format!(
+                                "literal of operation {} in result of a rewrite must have a type",
+                                dag.name()
+                            ),
+                        ));
+                    };
+                    let value = match literal.value() {
+                        LiteralValue::Integer(value) => quote! {
+                            melior::ir::attribute::IntegerAttribute::new(#r#type, #value).into()
+                        },
+                        LiteralValue::Float(value) => quote! {
+                            melior::ir::attribute::FloatAttribute::new(context, #r#type, #value).into()
+                        },
+                    };
+
+                    attributes.push(quote! {
+                        (melior::ir::Identifier::new(context, "value"), #value)
+                    });
+                }
+
+                for (name, value) in dag.attributes() {
+                    let name = name.to_string();
+                    let attribute = self.parse_attribute(value);
+
+                    attributes.push(quote! {
+                        (
+                            melior::ir::Identifier::new(context, #name),
+                            unsafe { melior::ir::Attribute::from_raw(#attribute) },
+                        )
+                    });
+                }
+
+                let name = dag.name();
+                let operation = self.create_identifier("operation");
+                let value = self.create_identifier("value");
+
+                statements.extend(quote! {
+                    let Ok(#operation) =
+                        melior::ir::operation::OperationBuilder::new(#name, operation.location())
+                            .add_operands(&[#(#operands),*])
+                            .add_attributes(&[#(#attributes),*])
+                            .enable_result_type_inference()
+                            .build()
+                    else {
+                        return false;
+                    };
+                    let Ok(#value) = #operation.result(0).map(|result| result.to_raw()) else {
+                        return false;
+                    };
+                });
+                self.operations.push(operation);
+                names.push(name.into());
+
+                Ok((
+                    statements,
+                    quote!(unsafe { melior::ir::Value::from_raw(#value) }),
+                ))
+            }
+            Dag::Variable(name) => {
+                let identifier = create_variable_identifier(name);
+
+                Ok((quote! {}, quote!(#identifier)))
+            }
+        }
+    }
+
+    fn parse_attribute(&mut self, value: &LitStr) -> Ident {
+        let identifier = self.create_identifier("attribute");
+
+        self.attributes.push((identifier.clone(), value.clone()));
+
+        identifier
+    }
+
+    fn parse_type(&mut self, value: &LitStr) -> Ident {
+        let identifier = self.create_identifier("type");
+
+        self.types.push((identifier.clone(), value.clone()));
+
+        identifier
+    }
+
+    fn create_identifier(&mut self, prefix: &str) -> Ident {
+        self.index += 1;
+
+        format_ident!("{}_{}", prefix, self.index)
+    }
+}
+
+fn create_variable_identifier(name: &Ident) -> Ident {
+    format_ident!("variable_{}", name)
+}
+
+fn find_literal(dag: &OperationDag) -> Result<Option<&Literal>, Error> {
+    let mut literals = dag
+        .arguments()
+        .iter()
+        .filter_map(|argument| match argument {
+            Dag::Literal(literal) => Some(literal),
+            _ => None,
+        });
+    let literal = literals.next();
+
+    if literals.next().is_some() {
+        return Err(Error::new(
+            Span::call_site(),
+            format!("operation {} has more than one literal", dag.name()),
+        ));
+    }
+
+    Ok(literal)
+}
+
+fn count_variables(dag: &Dag, counts: &mut HashMap<String, usize>) {
+    match dag {
+        Dag::Literal(_) => {}
+        Dag::Operation(dag) => {
+            for argument in dag.arguments() {
+                count_variables(argument, counts);
+            }
+        }
+        Dag::Variable(name) => *counts.entry(name.to_string()).or_default() += 1,
+    }
+}
+
+fn collect_variables(dag: &Dag, variables: &mut HashSet<String>) {
+    match dag {
+        Dag::Literal(_) => {}
+        Dag::Operation(dag) => {
+            for argument in dag.arguments() {
+                collect_variables(argument, variables);
+            }
+        }
+        Dag::Variable(name) => {
+            variables.insert(name.to_string());
+        }
+    }
+}
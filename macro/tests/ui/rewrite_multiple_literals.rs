@@ -0,0 +1,3 @@
+fn main() {
+    melior::rewrite!(context, (arith.addi $x, (arith.constant 1, 2)) => $x);
+}
@@ -0,0 +1,3 @@
+fn main() {
+    melior::rewrite!(context, (arith.addi $x, $x) => $y);
+}
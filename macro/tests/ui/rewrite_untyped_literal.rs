@@ -0,0 +1,3 @@
+fn main() {
+    melior::rewrite!(context, (arith.muli $x, $y) => (arith.addi $x, (arith.constant 1)));
+}
@@ -104,6 +104,9 @@ impl Context {
     }
 
     /// Attaches a diagnostic handler.
+    ///
+    /// See also [`DiagnosticHandlerGuard`](crate::diagnostic::DiagnosticHandlerGuard)
+    /// to detach a handler automatically.
     pub fn attach_diagnostic_handler<F: FnMut(Diagnostic) -> bool>(
         &self,
         handler: F,
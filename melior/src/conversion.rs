@@ -0,0 +1,567 @@
+//! Dialect conversion.
+//!
+//! Unlike the greedy rewrite driver, the conversion driver legalizes
+//! operations against a [`ConversionTarget`] and converts types of operands
+//! with a [`TypeConverter`] before they are passed to conversion patterns.
+//!
+//! Values whose types are changed by conversion are bridged with
+//! materializations of a type converter or, if none succeeds,
+//! `builtin.unrealized_conversion_cast` operations. Pairs of such casts that
+//! cancel each other out are removed after conversion.
+//!
+//! Operations are legalized with a worklist. Patterns must modify IR through
+//! the given rewriter so that the driver can track new and erased operations.
+//!
+//! The driver does not roll back IR when conversion fails. Operations
+//! converted before a failure remain converted.
+
+mod pattern;
+mod target;
+mod type_converter;
+
+pub use self::{
+    pattern::{ConversionPattern, ConversionPatternRewriter, ConversionPatternSet},
+    target::ConversionTarget,
+    type_converter::TypeConverter,
+};
+use crate::{
+    Error, IrRewriter, RewriterBase, RewriterListener,
+    diagnostic::{DiagnosticSeverity, OwnedDiagnostic},
+    ir::{
+        Location, OperationRef, Type, Value, ValueLike,
+        operation::{OperationBuilder, OperationLike, OperationResult, WalkOrder, WalkResult},
+    },
+};
+use mlir_sys::MlirOperation;
+use std::{cell::RefCell, collections::HashSet, ffi::c_void};
+
+const CAST_OPERATION_NAME: &str = "builtin.unrealized_conversion_cast";
+
+/// Applies a partial conversion to an operation and its nested operations.
+///
+/// Operations of unknown legality are converted if possible but left as they
+/// are otherwise. On failure, an error contains diagnostics for operations
+/// that remain illegal.
+pub fn apply_partial_conversion<'c>(
+    operation: OperationRef<'c, '_>,
+    target: &ConversionTarget<'c>,
+    patterns: &mut ConversionPatternSet<'c>,
+) -> Result<(), Error> {
+    apply_conversion(operation, target, patterns, false)
+}
+
+/// Applies a full conversion to an operation and its nested operations.
+///
+/// All operations including the given one must be legal after conversion. On
+/// failure, an error contains diagnostics for operations that are not legal.
+///
+/// Unlike the full conversion of MLIR, IR is not rolled back on failure.
+pub fn apply_full_conversion<'c>(
+    operation: OperationRef<'c, '_>,
+    target: &ConversionTarget<'c>,
+    patterns: &mut ConversionPatternSet<'c>,
+) -> Result<(), Error> {
+    apply_conversion(operation, target, patterns, true)
+}
+
+fn apply_conversion<'c>(
+    operation: OperationRef<'c, '_>,
+    target: &ConversionTarget<'c>,
+    patterns: &mut ConversionPatternSet<'c>,
+    full: bool,
+) -> Result<(), Error> {
+    let ir_rewriter = IrRewriter::new(unsafe { operation.context().to_ref() });
+    let casts = RefCell::new(HashSet::new());
+    let listener = RefCell::new(ConversionListener {
+        listener: patterns
+            .listener()
+            .map(|listener| listener as *const RefCell<dyn RewriterListener + 'c>),
+        events: vec![],
+    });
+    let rewriter = ir_rewriter
+        .as_rewriter_base()
+        .with_listener(Some(&listener));
+
+    // Operations are removed from the pending set when they are legalized or
+    // erased so that every operation is legalized at most once, even if a new
+    // operation reuses the address of an erased one.
+    let mut worklist = collect_operations(operation);
+    let mut pending = worklist
+        .iter()
+        .map(|operation| operation.ptr)
+        .collect::<HashSet<_>>();
+
+    pending.remove(&operation.to_raw().ptr);
+    worklist.reverse();
+
+    while let Some(raw) = worklist.pop() {
+        if !pending.remove(&raw.ptr) || casts.borrow().contains(&raw.ptr) {
+            continue;
+        }
+
+        let current = unsafe { OperationRef::from_raw(raw) };
+
+        if target.is_legal(current) == Some(true) {
+            continue;
+        }
+
+        patterns.match_and_rewrite(current, &rewriter, &casts);
+
+        for event in listener.borrow_mut().events.drain(..) {
+            match event {
+                ConversionEvent::Inserted(operation) => {
+                    pending.insert(operation.ptr);
+                    worklist.push(operation);
+                }
+                ConversionEvent::Erased(operation) => {
+                    pending.remove(&operation.ptr);
+                    casts.borrow_mut().remove(&operation.ptr);
+                }
+            }
+        }
+    }
+
+    for event in listener.borrow_mut().events.drain(..) {
+        if let ConversionEvent::Erased(operation) = event {
+            casts.borrow_mut().remove(&operation.ptr);
+        }
+    }
+
+    reconcile_casts(
+        &ir_rewriter
+            .as_rewriter_base()
+            .with_listener(patterns.listener()),
+        &casts,
+    );
+
+    let diagnostics = collect_operations(operation)
+        .into_iter()
+        .filter(|raw| !casts.borrow().contains(&raw.ptr))
+        .map(|raw| unsafe { OperationRef::from_raw(raw) })
+        .filter(|operation| match target.is_legal(*operation) {
+            Some(legal) => !legal,
+            None => full,
+        })
+        .map(|operation| {
+            OwnedDiagnostic::new(
+                format!(
+                    "failed to legalize operation '{}'",
+                    operation
+                        .name()
+                        .as_string_ref()
+                        .as_str()
+                        .unwrap_or_default()
+                ),
+                DiagnosticSeverity::Error,
+                operation.location(),
+                vec![],
+            )
+            .into()
+        })
+        .collect::<Vec<_>>();
+
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::ApplyConversion(diagnostics))
+    }
+}
+
+enum ConversionEvent {
+    Inserted(MlirOperation),
+    Erased(MlirOperation),
+}
+
+/// A listener tracking operations inserted and erased during conversion.
+///
+/// It forwards notifications to a listener of a pattern set.
+struct ConversionListener<'c> {
+    // A listener is owned by a pattern set borrowed during conversion.
+    listener: Option<*const RefCell<dyn RewriterListener + 'c>>,
+    events: Vec<ConversionEvent>,
+}
+
+impl ConversionListener<'_> {
+    fn notify(&self, notify: impl FnOnce(&mut dyn RewriterListener)) {
+        if let Some(listener) = self.listener {
+            notify(&mut *unsafe { &*listener }.borrow_mut());
+        }
+    }
+}
+
+impl RewriterListener for ConversionListener<'_> {
+    fn notify_operation_inserted(&mut self, operation: OperationRef) {
+        // Nested operations are collected now as the operation might be
+        // erased before events are processed.
+        self.events.extend(
+            collect_operations(operation)
+                .into_iter()
+                .map(ConversionEvent::Inserted),
+        );
+        self.notify(|listener| listener.notify_operation_inserted(operation));
+    }
+
+    fn notify_operation_modified(&mut self, operation: OperationRef) {
+        self.notify(|listener| listener.notify_operation_modified(operation));
+    }
+
+    fn notify_operation_replaced(&mut self, operation: OperationRef, values: &[Value]) {
+        self.notify(|listener| listener.notify_operation_replaced(operation, values));
+    }
+
+    fn notify_operation_erased(&mut self, operation: OperationRef) {
+        self.events
+            .push(ConversionEvent::Erased(operation.to_raw()));
+        self.notify(|listener| listener.notify_operation_erased(operation));
+    }
+
+    fn notify_match_failure(&mut self, location: Location, message: &str) {
+        self.notify(|listener| listener.notify_match_failure(location, message));
+    }
+}
+
+fn collect_operations(operation: OperationRef) -> Vec<MlirOperation> {
+    let mut operations = vec![];
+
+    operation.walk(WalkOrder::PreOrder, |operation| {
+        operations.push(operation.to_raw());
+        WalkResult::Advance
+    });
+
+    operations
+}
+
+fn convert_operand<'c, 'a>(
+    rewriter: &'a RewriterBase<'c, '_>,
+    type_converter: &TypeConverter<'c>,
+    operand: Value<'c, '_>,
+    r#type: Type<'c>,
+    location: Location<'c>,
+    casts: &RefCell<HashSet<*mut c_void>>,
+) -> Value<'c, 'a> {
+    if r#type == operand.r#type() {
+        return unsafe { Value::from_raw(operand.to_raw()) };
+    }
+
+    if let Some(input) = cast_input(operand, casts) {
+        if input.r#type() == r#type {
+            return unsafe { Value::from_raw(input.to_raw()) };
+        }
+    }
+
+    type_converter
+        .materialize_target(rewriter, r#type, &[operand], location)
+        .unwrap_or_else(|| create_cast(rewriter, r#type, operand, location, casts))
+}
+
+fn create_cast<'c, 'a>(
+    rewriter: &'a RewriterBase<'c, '_>,
+    r#type: Type<'c>,
+    value: Value<'c, '_>,
+    location: Location<'c>,
+    casts: &RefCell<HashSet<*mut c_void>>,
+) -> Value<'c, 'a> {
+    let operation = rewriter.insert(
+        OperationBuilder::new(CAST_OPERATION_NAME, location)
+            .add_operands(&[value])
+            .add_results(&[r#type])
+            .build()
+            .expect("valid unrealized conversion cast"),
+    );
+
+    casts.borrow_mut().insert(operation.to_raw().ptr);
+
+    operation.result(0).expect("cast result").into()
+}
+
+fn cast_input<'c, 'a>(
+    value: Value<'c, 'a>,
+    casts: &RefCell<HashSet<*mut c_void>>,
+) -> Option<Value<'c, 'a>> {
+    let owner = OperationResult::try_from(value).ok()?.owner();
+
+    if casts.borrow().contains(&owner.to_raw().ptr) {
+        owner.operand(0).ok()
+    } else {
+        None
+    }
+}
+
+fn reconcile_casts(rewriter: &RewriterBase, casts: &RefCell<HashSet<*mut c_void>>) {
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+
+        let pointers = casts.borrow().iter().copied().collect::<Vec<_>>();
+
+        for pointer in pointers {
+            let cast = unsafe { OperationRef::from_raw(MlirOperation { ptr: pointer }) };
+            let result = cast.result(0).expect("cast result");
+
+            if let Some(input) = cast_input(cast.operand(0).expect("cast operand"), casts) {
+                if input.r#type() == result.r#type() {
+                    result.replace_all_uses_with(input);
+                }
+            }
+
+            if result.is_unused() {
+                casts.borrow_mut().remove(&pointer);
+                rewriter.erase_op(cast);
+                changed = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Context,
+        dialect::arith,
+        ir::{Module, r#type::IntegerType},
+        test::create_test_context,
+    };
+
+    fn create_context() -> Context {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        context
+    }
+
+    #[test]
+    fn partial_conversion() {
+        let context = create_context();
+        let module = Module::parse(
+            &context,
+            r#"
+            func.func @foo(%arg0: index) -> index {
+              %0 = "foo.add"(%arg0, %arg0) : (index, index) -> index
+              return %0 : index
+            }
+            "#,
+        )
+        .unwrap();
+
+        let mut target = ConversionTarget::new();
+        target.add_illegal_dialect("foo");
+
+        let mut converter = TypeConverter::new();
+        converter.add_conversion(Some);
+
+        let mut patterns = ConversionPatternSet::new(converter);
+        patterns.add_pattern(
+            "foo.add",
+            1,
+            |operation: OperationRef, operands: &[Value], rewriter: &ConversionPatternRewriter| {
+                let add = rewriter.as_rewriter_base().insert(arith::addi(
+                    operands[0],
+                    operands[1],
+                    operation.location(),
+                ));
+
+                rewriter.replace_op(operation, &[add.result(0).unwrap().into()]);
+            },
+        );
+
+        apply_partial_conversion(module.as_operation(), &target, &mut patterns).unwrap();
+
+        let string = module.as_operation().to_string();
+
+        assert!(string.contains("arith.addi"));
+        assert!(!string.contains("foo.add"));
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn partial_conversion_with_types() {
+        let context = create_context();
+        let module = Module::parse(
+            &context,
+            r#"
+            func.func @foo(%arg0: i32) -> i32 {
+              %0 = "foo.neg"(%arg0) : (i32) -> i32
+              return %0 : i32
+            }
+            "#,
+        )
+        .unwrap();
+        let i32_type = IntegerType::new(&context, 32).into();
+        let i64_type = IntegerType::new(&context, 64).into();
+
+        let mut target = ConversionTarget::new();
+        target.add_illegal_operation("foo.neg");
+
+        let mut converter = TypeConverter::new();
+        converter.add_conversion(Some);
+        converter.add_conversion(move |r#type| (r#type == i32_type).then_some(i64_type));
+
+        let mut patterns = ConversionPatternSet::new(converter);
+        patterns.add_pattern(
+            "foo.neg",
+            1,
+            move |operation: OperationRef,
+                  operands: &[Value],
+                  rewriter: &ConversionPatternRewriter| {
+                assert_eq!(operands[0].r#type(), i64_type);
+
+                let negation = rewriter.as_rewriter_base().insert(
+                    OperationBuilder::new("bar.neg", operation.location())
+                        .add_operands(operands)
+                        .add_results(&[i64_type])
+                        .build()
+                        .unwrap(),
+                );
+
+                rewriter.replace_op(operation, &[negation.result(0).unwrap().into()]);
+            },
+        );
+
+        apply_partial_conversion(module.as_operation(), &target, &mut patterns).unwrap();
+
+        let string = module.as_operation().to_string();
+
+        assert!(string.contains("bar.neg"));
+        assert!(string.contains(CAST_OPERATION_NAME));
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn partial_conversion_without_match() {
+        struct Pattern;
+
+        impl<'c> ConversionPattern<'c> for Pattern {
+            fn matches(&mut self, _operation: OperationRef<'c, '_>) -> bool {
+                false
+            }
+
+            fn rewrite(
+                &mut self,
+                _operation: OperationRef<'c, '_>,
+                _operands: &[Value<'c, '_>],
+                _rewriter: &ConversionPatternRewriter<'c, '_>,
+            ) {
+                unreachable!()
+            }
+        }
+
+        let context = create_context();
+        let source = r#"
+            func.func @foo(%arg0: i32) -> i32 {
+              %0 = "foo.neg"(%arg0) : (i32) -> i32
+              return %0 : i32
+            }
+            "#;
+        let module = Module::parse(&context, source).unwrap();
+        let i32_type = IntegerType::new(&context, 32).into();
+        let i64_type = IntegerType::new(&context, 64).into();
+
+        let mut target = ConversionTarget::new();
+        target.add_illegal_operation("foo.neg");
+
+        let mut converter = TypeConverter::new();
+        converter.add_conversion(Some);
+        converter.add_conversion(move |r#type| (r#type == i32_type).then_some(i64_type));
+
+        let mut patterns = ConversionPatternSet::new(converter);
+        patterns.add_pattern("foo.neg", 1, Pattern);
+
+        assert!(apply_partial_conversion(module.as_operation(), &target, &mut patterns).is_err());
+        assert_eq!(
+            module.as_operation().to_string(),
+            Module::parse(&context, source)
+                .unwrap()
+                .as_operation()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn partial_conversion_of_new_operations() {
+        let context = create_context();
+        let module = Module::parse(
+            &context,
+            r#"
+            func.func @foo(%arg0: index) -> index {
+              %0 = "foo.add"(%arg0, %arg0) : (index, index) -> index
+              return %0 : index
+            }
+            "#,
+        )
+        .unwrap();
+
+        let mut target = ConversionTarget::new();
+        target.add_illegal_dialect("foo");
+
+        let mut converter = TypeConverter::new();
+        converter.add_conversion(Some);
+
+        let mut patterns = ConversionPatternSet::new(converter);
+
+        for (from, to) in [("foo.add", "foo.sum"), ("foo.sum", "bar.sum")] {
+            patterns.add_pattern(
+                from,
+                1,
+                move |operation: OperationRef,
+                      operands: &[Value],
+                      rewriter: &ConversionPatternRewriter| {
+                    let sum = rewriter.as_rewriter_base().insert(
+                        OperationBuilder::new(to, operation.location())
+                            .add_operands(operands)
+                            .add_results(&[operation.result(0).unwrap().r#type()])
+                            .build()
+                            .unwrap(),
+                    );
+
+                    rewriter.replace_op(operation, &[sum.result(0).unwrap().into()]);
+                },
+            );
+        }
+
+        apply_partial_conversion(module.as_operation(), &target, &mut patterns).unwrap();
+
+        let string = module.as_operation().to_string();
+
+        assert!(string.contains("bar.sum"));
+        assert!(!string.contains("foo."));
+    }
+
+    #[test]
+    fn full_conversion_failure() {
+        let context = create_context();
+        let module = Module::parse(
+            &context,
+            r#"
+            func.func @foo(%arg0: index) -> index {
+              %0 = "foo.add"(%arg0, %arg0) : (index, index) -> index
+              return %0 : index
+            }
+            "#,
+        )
+        .unwrap();
+
+        let mut target = ConversionTarget::new();
+        target.add_legal_dialect("builtin");
+        target.add_legal_dialect("func");
+
+        let mut patterns = ConversionPatternSet::new(TypeConverter::new());
+
+        assert_eq!(
+            apply_partial_conversion(module.as_operation(), &target, &mut patterns),
+            Ok(())
+        );
+
+        let Err(Error::ApplyConversion(diagnostics)) =
+            apply_full_conversion(module.as_operation(), &target, &mut patterns)
+        else {
+            panic!("conversion error expected");
+        };
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message(),
+            "failed to legalize operation 'foo.add'"
+        );
+    }
+}
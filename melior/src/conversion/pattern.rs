@@ -0,0 +1,222 @@
+use super::{TypeConverter, convert_operand, create_cast};
+use crate::{
+    RewriterBase, RewriterListener,
+    ir::{OperationRef, Value, ValueLike, operation::OperationLike},
+};
+use std::{cell::RefCell, collections::HashSet, ffi::c_void};
+
+/// A conversion pattern.
+///
+/// Unlike [`OpRewritePattern`](crate::OpRewritePattern), it receives operands
+/// already converted by a type converter. Operands are converted only after a
+/// pattern matches an operation so that IR is not modified otherwise.
+///
+/// Closures of `FnMut(OperationRef, &[Value], &ConversionPatternRewriter)`
+/// implement this trait and match every operation with their root names.
+pub trait ConversionPattern<'c> {
+    /// Returns `true` if a pattern matches an operation.
+    ///
+    /// It must not modify IR.
+    fn matches(&mut self, _operation: OperationRef<'c, '_>) -> bool {
+        true
+    }
+
+    /// Rewrites a matched operation.
+    ///
+    /// It must replace, erase, or update the operation in place.
+    fn rewrite(
+        &mut self,
+        operation: OperationRef<'c, '_>,
+        operands: &[Value<'c, '_>],
+        rewriter: &ConversionPatternRewriter<'c, '_>,
+    );
+}
+
+impl<'c, F> ConversionPattern<'c> for F
+where
+    F: FnMut(OperationRef<'c, '_>, &[Value<'c, '_>], &ConversionPatternRewriter<'c, '_>),
+{
+    fn rewrite(
+        &mut self,
+        operation: OperationRef<'c, '_>,
+        operands: &[Value<'c, '_>],
+        rewriter: &ConversionPatternRewriter<'c, '_>,
+    ) {
+        self(operation, operands, rewriter)
+    }
+}
+
+/// A set of conversion patterns sharing a type converter.
+pub struct ConversionPatternSet<'c> {
+    type_converter: TypeConverter<'c>,
+    patterns: Vec<(String, u32, Box<dyn ConversionPattern<'c> + 'c>)>,
+    listener: Option<Box<RefCell<dyn RewriterListener + 'c>>>,
+}
+
+impl<'c> ConversionPatternSet<'c> {
+    /// Creates a conversion pattern set.
+    pub fn new(type_converter: TypeConverter<'c>) -> Self {
+        Self {
+            type_converter,
+            patterns: vec![],
+            listener: None,
+        }
+    }
+
+    /// Returns a type converter.
+    pub const fn type_converter(&self) -> &TypeConverter<'c> {
+        &self.type_converter
+    }
+
+    /// Adds a conversion pattern matching operations with a root name.
+    ///
+    /// Patterns with higher benefits are tried first.
+    pub fn add_pattern(
+        &mut self,
+        root_name: &str,
+        benefit: u32,
+        pattern: impl ConversionPattern<'c> + 'c,
+    ) {
+        let index = self
+            .patterns
+            .partition_point(|(_, other, _)| *other >= benefit);
+
+        self.patterns
+            .insert(index, (root_name.into(), benefit, Box::new(pattern)));
+    }
+
+    /// Sets a listener notified of changes made during conversion.
+    pub fn set_listener(&mut self, listener: impl RewriterListener + 'c) {
+        self.listener = Some(Box::new(RefCell::new(listener)));
+    }
+
+    pub(super) fn listener(&self) -> Option<&RefCell<dyn RewriterListener + 'c>> {
+        self.listener.as_deref()
+    }
+
+    pub(super) fn match_and_rewrite(
+        &mut self,
+        operation: OperationRef<'c, '_>,
+        rewriter: &RewriterBase<'c, '_>,
+        casts: &RefCell<HashSet<*mut c_void>>,
+    ) -> bool {
+        let name = operation.name();
+        let name = name.as_string_ref().as_str().unwrap_or_default();
+        let conversion_rewriter = ConversionPatternRewriter {
+            rewriter: *rewriter,
+            type_converter: &self.type_converter,
+            casts,
+        };
+
+        for (root_name, _, pattern) in &mut self.patterns {
+            if root_name != name || !pattern.matches(operation) {
+                continue;
+            }
+
+            let Some(types) = operation
+                .operands()
+                .map(|operand| self.type_converter.convert_type(operand.r#type()))
+                .collect::<Option<Vec<_>>>()
+            else {
+                return false;
+            };
+
+            rewriter.set_insertion_point_before(operation);
+
+            let operands = operation
+                .operands()
+                .zip(types)
+                .map(|(operand, r#type)| {
+                    convert_operand(
+                        rewriter,
+                        &self.type_converter,
+                        operand,
+                        r#type,
+                        operation.location(),
+                        casts,
+                    )
+                    .to_raw()
+                })
+                .collect::<Vec<_>>();
+            let operands = operands
+                .into_iter()
+                .map(|raw| unsafe { Value::from_raw(raw) })
+                .collect::<Vec<_>>();
+
+            rewriter.set_insertion_point_before(operation);
+            pattern.rewrite(operation, &operands, &conversion_rewriter);
+
+            return true;
+        }
+
+        false
+    }
+}
+
+/// A rewriter available inside conversion patterns.
+///
+/// New operations are inserted before a matched operation by default.
+pub struct ConversionPatternRewriter<'c, 'a> {
+    rewriter: RewriterBase<'c, 'a>,
+    type_converter: &'a TypeConverter<'c>,
+    casts: &'a RefCell<HashSet<*mut c_void>>,
+}
+
+impl<'c, 'a> ConversionPatternRewriter<'c, 'a> {
+    /// Returns the underlying rewriter base.
+    pub const fn as_rewriter_base(&self) -> RewriterBase<'c, 'a> {
+        self.rewriter
+    }
+
+    /// Returns a type converter.
+    pub const fn type_converter(&self) -> &'a TypeConverter<'c> {
+        self.type_converter
+    }
+
+    /// Replaces results of an operation with values and erases the operation.
+    ///
+    /// If types of values are different from types of the results, values are
+    /// materialized back into the original types for the remaining users.
+    pub fn replace_op(&self, operation: OperationRef<'c, '_>, values: &[Value<'c, '_>]) {
+        self.rewriter.set_insertion_point_before(operation);
+
+        let values = operation
+            .results()
+            .zip(values)
+            .map(|(result, value)| {
+                if result.r#type() == value.r#type() {
+                    value.to_raw()
+                } else {
+                    self.type_converter
+                        .materialize_source(
+                            &self.rewriter,
+                            result.r#type(),
+                            &[*value],
+                            operation.location(),
+                        )
+                        .unwrap_or_else(|| {
+                            create_cast(
+                                &self.rewriter,
+                                result.r#type(),
+                                *value,
+                                operation.location(),
+                                self.casts,
+                            )
+                        })
+                        .to_raw()
+                }
+            })
+            .collect::<Vec<_>>();
+        let values = values
+            .into_iter()
+            .map(|raw| unsafe { Value::from_raw(raw) })
+            .collect::<Vec<_>>();
+
+        self.rewriter.replace_op_with_values(operation, &values);
+    }
+
+    /// Erases an operation.
+    pub fn erase_op(&self, operation: OperationRef<'c, '_>) {
+        self.rewriter.erase_op(operation);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::ir::{OperationRef, operation::OperationLike};
+use std::collections::HashMap;
+
+enum Legality<'c> {
+    Legal,
+    Illegal,
+    Dynamic(Box<dyn Fn(OperationRef<'c, '_>) -> bool + 'c>),
+}
+
+impl<'c> Legality<'c> {
+    fn is_legal(&self, operation: OperationRef<'c, '_>) -> bool {
+        match self {
+            Self::Legal => true,
+            Self::Illegal => false,
+            Self::Dynamic(callback) => callback(operation),
+        }
+    }
+}
+
+/// A conversion target.
+///
+/// It describes which operations are legal after conversion. Rules for
+/// operations take precedence over rules for their dialects. Operations
+/// without any rule have unknown legality.
+#[derive(Default)]
+pub struct ConversionTarget<'c> {
+    dialects: HashMap<String, Legality<'c>>,
+    operations: HashMap<String, Legality<'c>>,
+}
+
+impl<'c> ConversionTarget<'c> {
+    /// Creates a conversion target.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks a dialect legal.
+    pub fn add_legal_dialect(&mut self, name: &str) {
+        self.dialects.insert(name.into(), Legality::Legal);
+    }
+
+    /// Marks a dialect illegal.
+    pub fn add_illegal_dialect(&mut self, name: &str) {
+        self.dialects.insert(name.into(), Legality::Illegal);
+    }
+
+    /// Marks a dialect dynamically legal.
+    ///
+    /// Operations in the dialect are legal if a callback returns `true` for
+    /// them.
+    pub fn add_dynamically_legal_dialect(
+        &mut self,
+        name: &str,
+        callback: impl Fn(OperationRef<'c, '_>) -> bool + 'c,
+    ) {
+        self.dialects
+            .insert(name.into(), Legality::Dynamic(Box::new(callback)));
+    }
+
+    /// Marks an operation legal.
+    pub fn add_legal_operation(&mut self, name: &str) {
+        self.operations.insert(name.into(), Legality::Legal);
+    }
+
+    /// Marks an operation illegal.
+    pub fn add_illegal_operation(&mut self, name: &str) {
+        self.operations.insert(name.into(), Legality::Illegal);
+    }
+
+    /// Marks an operation dynamically legal.
+    ///
+    /// The operation is legal if a callback returns `true` for it.
+    pub fn add_dynamically_legal_operation(
+        &mut self,
+        name: &str,
+        callback: impl Fn(OperationRef<'c, '_>) -> bool + 'c,
+    ) {
+        self.operations
+            .insert(name.into(), Legality::Dynamic(Box::new(callback)));
+    }
+
+    /// Returns `true` if an operation is legal, `false` if it is illegal, or
+    /// `None` if its legality is unknown.
+    pub fn is_legal(&self, operation: OperationRef<'c, '_>) -> Option<bool> {
+        let name = operation.name();
+        let name = name.as_string_ref().as_str().unwrap_or_default();
+
+        self.operations
+            .get(name)
+            .or_else(|| {
+                self.dialects
+                    .get(name.split_once('.').map_or(name, |(dialect, _)| dialect))
+            })
+            .map(|legality| legality.is_legal(operation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{BlockLike, Location, Module, Type, operation::OperationBuilder},
+        test::create_test_context,
+    };
+
+    #[test]
+    fn is_legal() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+        let body = module.body();
+
+        let foo =
+            body.append_operation(OperationBuilder::new("foo.foo", location).build().unwrap());
+        let bar =
+            body.append_operation(OperationBuilder::new("foo.bar", location).build().unwrap());
+        let baz =
+            body.append_operation(OperationBuilder::new("baz.baz", location).build().unwrap());
+
+        let mut target = ConversionTarget::new();
+
+        target.add_illegal_dialect("foo");
+        target.add_legal_operation("foo.bar");
+
+        assert_eq!(target.is_legal(foo), Some(false));
+        assert_eq!(target.is_legal(bar), Some(true));
+        assert_eq!(target.is_legal(baz), None);
+    }
+
+    #[test]
+    fn is_legal_dynamically() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+        let body = module.body();
+
+        let foo =
+            body.append_operation(OperationBuilder::new("foo.foo", location).build().unwrap());
+        let bar = body.append_operation(
+            OperationBuilder::new("foo.foo", location)
+                .add_results(&[Type::index(&context)])
+                .build()
+                .unwrap(),
+        );
+
+        let mut target = ConversionTarget::new();
+
+        target.add_dynamically_legal_dialect("foo", |operation| operation.result_count() == 0);
+
+        assert_eq!(target.is_legal(foo), Some(true));
+        assert_eq!(target.is_legal(bar), Some(false));
+    }
+}
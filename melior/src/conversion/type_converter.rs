@@ -0,0 +1,234 @@
+use crate::{
+    RewriterBase,
+    ir::{Location, OperationRef, Type, Value, ValueLike, operation::OperationLike},
+};
+
+type Conversion<'c> = Box<dyn Fn(Type<'c>) -> Option<Type<'c>> + 'c>;
+
+type Materialization<'c> = Box<
+    dyn for<'a> Fn(
+            &'a RewriterBase<'c, '_>,
+            Type<'c>,
+            &[Value<'c, '_>],
+            Location<'c>,
+        ) -> Option<Value<'c, 'a>>
+        + 'c,
+>;
+
+/// A type converter.
+///
+/// Conversions and materializations are tried in the reverse order of their
+/// addition until one of them succeeds.
+#[derive(Default)]
+pub struct TypeConverter<'c> {
+    conversions: Vec<Conversion<'c>>,
+    source_materializations: Vec<Materialization<'c>>,
+    target_materializations: Vec<Materialization<'c>>,
+}
+
+impl<'c> TypeConverter<'c> {
+    /// Creates a type converter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a type conversion.
+    ///
+    /// A conversion returns `None` if it cannot convert a type.
+    pub fn add_conversion(&mut self, conversion: impl Fn(Type<'c>) -> Option<Type<'c>> + 'c) {
+        self.conversions.push(Box::new(conversion));
+    }
+
+    /// Adds a source materialization.
+    ///
+    /// It converts values of converted types back into values of their
+    /// original types for users that are not converted yet. It inserts
+    /// operations with a rewriter and returns `None` on failure.
+    pub fn add_source_materialization<F>(&mut self, materialization: F)
+    where
+        F: for<'a> Fn(
+                &'a RewriterBase<'c, '_>,
+                Type<'c>,
+                &[Value<'c, '_>],
+                Location<'c>,
+            ) -> Option<Value<'c, 'a>>
+            + 'c,
+    {
+        self.source_materializations.push(Box::new(materialization));
+    }
+
+    /// Adds a target materialization.
+    ///
+    /// It converts values of original types into values of converted types
+    /// for operands passed to conversion patterns. It inserts operations with
+    /// a rewriter and returns `None` on failure.
+    pub fn add_target_materialization<F>(&mut self, materialization: F)
+    where
+        F: for<'a> Fn(
+                &'a RewriterBase<'c, '_>,
+                Type<'c>,
+                &[Value<'c, '_>],
+                Location<'c>,
+            ) -> Option<Value<'c, 'a>>
+            + 'c,
+    {
+        self.target_materializations.push(Box::new(materialization));
+    }
+
+    /// Converts a type.
+    pub fn convert_type(&self, r#type: Type<'c>) -> Option<Type<'c>> {
+        self.conversions
+            .iter()
+            .rev()
+            .find_map(|conversion| conversion(r#type))
+    }
+
+    /// Converts types.
+    pub fn convert_types(&self, types: &[Type<'c>]) -> Option<Vec<Type<'c>>> {
+        types
+            .iter()
+            .map(|r#type| self.convert_type(*r#type))
+            .collect()
+    }
+
+    /// Returns `true` if a type is converted into itself.
+    pub fn is_legal(&self, r#type: Type<'c>) -> bool {
+        self.convert_type(r#type) == Some(r#type)
+    }
+
+    /// Returns `true` if types of all operands and results of an operation are
+    /// legal.
+    pub fn is_legal_operation(&self, operation: OperationRef<'c, '_>) -> bool {
+        operation
+            .operands()
+            .all(|operand| self.is_legal(operand.r#type()))
+            && operation
+                .results()
+                .all(|result| self.is_legal(result.r#type()))
+    }
+
+    /// Materializes a value of an original type from values of converted
+    /// types.
+    pub fn materialize_source<'a>(
+        &self,
+        rewriter: &'a RewriterBase<'c, '_>,
+        r#type: Type<'c>,
+        inputs: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) -> Option<Value<'c, 'a>> {
+        Self::materialize(
+            &self.source_materializations,
+            rewriter,
+            r#type,
+            inputs,
+            location,
+        )
+    }
+
+    /// Materializes a value of a converted type from values of original types.
+    pub fn materialize_target<'a>(
+        &self,
+        rewriter: &'a RewriterBase<'c, '_>,
+        r#type: Type<'c>,
+        inputs: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) -> Option<Value<'c, 'a>> {
+        Self::materialize(
+            &self.target_materializations,
+            rewriter,
+            r#type,
+            inputs,
+            location,
+        )
+    }
+
+    fn materialize<'a>(
+        materializations: &[Materialization<'c>],
+        rewriter: &'a RewriterBase<'c, '_>,
+        r#type: Type<'c>,
+        inputs: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) -> Option<Value<'c, 'a>> {
+        materializations
+            .iter()
+            .rev()
+            .find_map(|materialization| materialization(rewriter, r#type, inputs, location))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        IrRewriter,
+        ir::{BlockLike, Module, operation::OperationBuilder, r#type::IntegerType},
+        test::create_test_context,
+    };
+
+    #[test]
+    fn convert_type() {
+        let context = create_test_context();
+        let index_type = Type::index(&context);
+        let i32_type = IntegerType::new(&context, 32).into();
+        let i64_type = IntegerType::new(&context, 64).into();
+
+        let mut converter = TypeConverter::new();
+
+        assert_eq!(converter.convert_type(index_type), None);
+
+        converter.add_conversion(Some);
+        converter.add_conversion(move |r#type| (r#type == i32_type).then_some(i64_type));
+
+        assert_eq!(converter.convert_type(index_type), Some(index_type));
+        assert_eq!(converter.convert_type(i32_type), Some(i64_type));
+        assert_eq!(
+            converter.convert_types(&[i32_type, index_type]),
+            Some(vec![i64_type, index_type])
+        );
+        assert!(converter.is_legal(index_type));
+        assert!(!converter.is_legal(i32_type));
+    }
+
+    #[test]
+    fn materialize_source() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+        let i64_type = IntegerType::new(&context, 64).into();
+
+        let mut converter = TypeConverter::new();
+
+        converter.add_source_materialization(|rewriter, r#type, inputs, location| {
+            Some(
+                rewriter
+                    .insert(
+                        OperationBuilder::new("foo.cast", location)
+                            .add_operands(inputs)
+                            .add_results(&[r#type])
+                            .build()
+                            .ok()?,
+                    )
+                    .result(0)
+                    .ok()?
+                    .into(),
+            )
+        });
+
+        let rewriter = IrRewriter::new(&context);
+        let base = rewriter.as_rewriter_base();
+        base.set_insertion_point_to_end(module.body());
+
+        let value = converter
+            .materialize_source(&base, i64_type, &[], location)
+            .unwrap();
+
+        assert_eq!(value.r#type(), i64_type);
+        assert!(
+            converter
+                .materialize_target(&base, i64_type, &[], location)
+                .is_none()
+        );
+    }
+}
@@ -1,10 +1,19 @@
 //! Diagnostics.
 
+mod collector;
+mod detached;
+mod guard;
 mod handler_id;
+mod in_flight;
+mod owned;
 mod severity;
 
-pub use self::{handler_id::DiagnosticHandlerId, severity::DiagnosticSeverity};
-use crate::{Error, ir::Location, utility::print_callback};
+pub use self::{
+    collector::DiagnosticCollector, detached::DetachedDiagnostic, guard::DiagnosticHandlerGuard,
+    handler_id::DiagnosticHandlerId, in_flight::InFlightDiagnostic, owned::OwnedDiagnostic,
+    severity::DiagnosticSeverity,
+};
+use crate::{Context, Error, ir::Location, utility::print_callback};
 use mlir_sys::{
     MlirDiagnostic, mlirDiagnosticGetLocation, mlirDiagnosticGetNote, mlirDiagnosticGetNumNotes,
     mlirDiagnosticGetSeverity, mlirDiagnosticPrint,
@@ -13,6 +22,8 @@ use std::{
     ffi::c_void,
     fmt::{self, Display, Formatter},
     marker::PhantomData,
+    mem::take,
+    sync::{Arc, Mutex, PoisonError},
 };
 
 #[derive(Debug)]
@@ -76,9 +87,57 @@ impl Display for Diagnostic<'_> {
     }
 }
 
+/// Collects diagnostics emitted in a context during a function call.
+pub(crate) fn collect_diagnostics<'c, T>(
+    context: &'c Context,
+    function: impl FnOnce() -> T,
+) -> (T, Vec<OwnedDiagnostic<'c>>) {
+    let collector = DiagnosticCollector::new(context);
+    let value = function();
+
+    (value, collector.take())
+}
+
+/// Observes diagnostics emitted in a context during a function call.
+///
+/// Unlike [`collect_diagnostics`], it passes copies of diagnostics on to
+/// handlers attached earlier, so that they are still reported to users.
+/// Diagnostics emitted by other threads sharing the context during the call
+/// are observed as well.
+pub(crate) fn observe_diagnostics<'c, T>(
+    context: &'c Context,
+    function: impl FnOnce() -> T,
+) -> (T, Vec<OwnedDiagnostic<'c>>) {
+    // Multi-threaded passes can emit diagnostics from worker threads.
+    let diagnostics = Arc::new(Mutex::new(vec![]));
+    // SAFETY: The guard is dropped before returning.
+    let guard = unsafe {
+        DiagnosticHandlerGuard::new_unchecked(context, {
+            let diagnostics = diagnostics.clone();
+
+            move |diagnostic| {
+                diagnostics
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(OwnedDiagnostic::from(&diagnostic));
+
+                false
+            }
+        })
+    };
+    let value = function();
+
+    drop(guard);
+
+    let diagnostics = take(&mut *diagnostics.lock().unwrap_or_else(PoisonError::into_inner));
+
+    (value, diagnostics)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Context, ir::Module};
+    use super::*;
+    use crate::ir::Module;
 
     #[test]
     fn handle_diagnostic() {
@@ -97,4 +156,45 @@ mod tests {
             "custom op 'foo' is unknown (tried 'builtin.foo' as well)"
         );
     }
+
+    #[test]
+    fn collect_diagnostics_in_scope() {
+        let context = Context::new();
+
+        let (module, diagnostics) =
+            collect_diagnostics(&context, || Module::parse(&context, "foo"));
+
+        assert!(module.is_none());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message(),
+            "custom op 'foo' is unknown (tried 'builtin.foo' as well)"
+        );
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+
+        let (_, diagnostics) = collect_diagnostics(&context, || Module::parse(&context, ""));
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn observe_diagnostics_in_scope() {
+        let context = Context::new();
+        let mut count = 0;
+
+        let id = context.attach_diagnostic_handler(|_| {
+            count += 1;
+            true
+        });
+
+        let (module, diagnostics) =
+            observe_diagnostics(&context, || Module::parse(&context, "foo"));
+
+        context.detach_diagnostic_handler(id);
+
+        assert!(module.is_none());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+        assert_eq!(count, 1);
+    }
 }
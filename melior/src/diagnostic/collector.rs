@@ -0,0 +1,175 @@
+use super::{DiagnosticHandlerGuard, DiagnosticSeverity, OwnedDiagnostic};
+use crate::Context;
+use std::{cell::RefCell, rc::Rc};
+
+/// A diagnostic handler collecting diagnostics into a vector.
+///
+/// Diagnostics are collected until the collector is dropped.
+#[derive(Debug)]
+pub struct DiagnosticCollector<'c> {
+    _guard: DiagnosticHandlerGuard<'c>,
+    diagnostics: Rc<RefCell<Vec<OwnedDiagnostic<'c>>>>,
+}
+
+impl<'c> DiagnosticCollector<'c> {
+    /// Creates a diagnostic collector collecting all diagnostics.
+    pub fn new(context: &'c Context) -> Self {
+        Self::with_filter(context, |_| true)
+    }
+
+    /// Creates a diagnostic collector collecting diagnostics of given
+    /// severities.
+    ///
+    /// The other diagnostics are passed to handlers attached earlier.
+    pub fn with_severities(context: &'c Context, severities: &[DiagnosticSeverity]) -> Self {
+        let severities = severities.to_vec();
+
+        Self::with_filter(context, move |severity| severities.contains(&severity))
+    }
+
+    fn with_filter(
+        context: &'c Context,
+        filter: impl Fn(DiagnosticSeverity) -> bool + 'static,
+    ) -> Self {
+        let diagnostics = Rc::new(RefCell::new(vec![]));
+
+        Self {
+            // SAFETY: The handler owns its data and only refers to objects
+            // owned by the context, which are valid whenever it is called even
+            // if the collector is leaked.
+            _guard: unsafe {
+                DiagnosticHandlerGuard::new_unchecked(context, {
+                    let diagnostics = diagnostics.clone();
+
+                    move |diagnostic| {
+                        if !filter(diagnostic.severity()) {
+                            return false;
+                        }
+
+                        diagnostics
+                            .borrow_mut()
+                            .push(OwnedDiagnostic::from(&diagnostic));
+
+                        true
+                    }
+                })
+            },
+            diagnostics,
+        }
+    }
+
+    /// Returns collected diagnostics.
+    pub fn diagnostics(&self) -> Vec<OwnedDiagnostic<'c>> {
+        self.diagnostics.borrow().clone()
+    }
+
+    /// Takes collected diagnostics out of a collector.
+    pub fn take(&self) -> Vec<OwnedDiagnostic<'c>> {
+        self.diagnostics.take()
+    }
+
+    /// Returns `true` if any error has been collected.
+    pub fn has_errors(&self) -> bool {
+        self.has_severity(DiagnosticSeverity::Error)
+    }
+
+    /// Returns `true` if any warning has been collected.
+    pub fn has_warnings(&self) -> bool {
+        self.has_severity(DiagnosticSeverity::Warning)
+    }
+
+    /// Detaches a collector and returns collected diagnostics.
+    ///
+    /// It returns an error with the diagnostics if any error has been
+    /// collected, or any warning if `fail_on_warnings` is `true`.
+    pub fn finish(
+        self,
+        fail_on_warnings: bool,
+    ) -> Result<Vec<OwnedDiagnostic<'c>>, Vec<OwnedDiagnostic<'c>>> {
+        let failed = self.has_errors() || (fail_on_warnings && self.has_warnings());
+        let diagnostics = self.take();
+
+        if failed {
+            Err(diagnostics)
+        } else {
+            Ok(diagnostics)
+        }
+    }
+
+    fn has_severity(&self, severity: DiagnosticSeverity) -> bool {
+        self.diagnostics
+            .borrow()
+            .iter()
+            .any(|diagnostic| diagnostic.severity() == severity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{Location, Module};
+
+    #[test]
+    fn collect() {
+        let context = Context::new();
+        let collector = DiagnosticCollector::new(&context);
+
+        Module::parse(&context, "foo");
+
+        let diagnostics = collector.diagnostics();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message(),
+            "custom op 'foo' is unknown (tried 'builtin.foo' as well)"
+        );
+        assert!(collector.has_errors());
+        assert!(!collector.has_warnings());
+        assert_eq!(collector.take(), diagnostics);
+        assert!(collector.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn collect_with_severities() {
+        let context = Context::new();
+        let errors = DiagnosticCollector::new(&context);
+        let warnings =
+            DiagnosticCollector::with_severities(&context, &[DiagnosticSeverity::Warning]);
+
+        Module::parse(&context, "foo");
+
+        assert!(warnings.diagnostics().is_empty());
+        assert_eq!(errors.diagnostics().len(), 1);
+    }
+
+    #[test]
+    fn finish() {
+        let context = Context::new();
+        let collector = DiagnosticCollector::new(&context);
+
+        assert_eq!(collector.finish(true), Ok(vec![]));
+
+        let collector = DiagnosticCollector::new(&context);
+
+        Module::parse(&context, "foo");
+
+        assert_eq!(collector.finish(false).unwrap_err().len(), 1);
+    }
+
+    #[test]
+    fn finish_on_warnings() {
+        let context = Context::new();
+        let location = Location::unknown(&context);
+        let warning = OwnedDiagnostic::new("foo", DiagnosticSeverity::Warning, location, vec![]);
+
+        let collector = DiagnosticCollector::new(&context);
+        collector.diagnostics.borrow_mut().push(warning.clone());
+
+        assert_eq!(collector.finish(false), Ok(vec![warning.clone()]));
+
+        let collector = DiagnosticCollector::new(&context);
+        collector.diagnostics.borrow_mut().push(warning.clone());
+
+        assert_eq!(collector.finish(true), Err(vec![warning]));
+    }
+}
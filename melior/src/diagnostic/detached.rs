@@ -0,0 +1,69 @@
+use super::{DiagnosticSeverity, OwnedDiagnostic};
+use std::fmt::{self, Display, Formatter};
+
+/// A diagnostic detached from its context.
+///
+/// Its location is printed into a string so that it can outlive the context,
+/// such as in [`Error`](crate::Error).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DetachedDiagnostic {
+    message: String,
+    severity: DiagnosticSeverity,
+    location: String,
+    notes: Vec<DetachedDiagnostic>,
+}
+
+impl DetachedDiagnostic {
+    /// Returns a message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns a severity.
+    pub const fn severity(&self) -> DiagnosticSeverity {
+        self.severity
+    }
+
+    /// Returns a printed location.
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// Returns notes.
+    pub fn notes(&self) -> &[Self] {
+        &self.notes
+    }
+}
+
+impl From<&OwnedDiagnostic<'_>> for DetachedDiagnostic {
+    fn from(diagnostic: &OwnedDiagnostic) -> Self {
+        Self {
+            message: diagnostic.message().into(),
+            severity: diagnostic.severity(),
+            location: diagnostic.location().to_string(),
+            notes: diagnostic.notes().iter().map(Self::from).collect(),
+        }
+    }
+}
+
+impl From<OwnedDiagnostic<'_>> for DetachedDiagnostic {
+    fn from(diagnostic: OwnedDiagnostic) -> Self {
+        Self::from(&diagnostic)
+    }
+}
+
+impl Display for DetachedDiagnostic {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{}: {}: {}",
+            self.location, self.severity, self.message
+        )?;
+
+        for note in &self.notes {
+            write!(formatter, "\n{note}")?;
+        }
+
+        Ok(())
+    }
+}
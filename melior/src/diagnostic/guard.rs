@@ -0,0 +1,84 @@
+use super::{Diagnostic, DiagnosticHandlerId};
+use crate::Context;
+
+/// A guard of a diagnostic handler attached to a context.
+///
+/// The handler is detached from the context when the guard is dropped.
+#[derive(Debug)]
+pub struct DiagnosticHandlerGuard<'c> {
+    context: &'c Context,
+    id: DiagnosticHandlerId,
+}
+
+impl<'c> DiagnosticHandlerGuard<'c> {
+    /// Attaches a diagnostic handler to a context.
+    ///
+    /// A handler returns `true` if it has handled a diagnostic. Otherwise, the
+    /// diagnostic is passed to handlers attached earlier.
+    pub fn new(
+        context: &'c Context,
+        handler: impl FnMut(Diagnostic<'c>) -> bool + 'static,
+    ) -> Self {
+        unsafe { Self::new_unchecked(context, handler) }
+    }
+
+    /// Attaches a diagnostic handler borrowing data to a context.
+    ///
+    /// # Safety
+    ///
+    /// Data borrowed by a handler must outlive the handler attached to a
+    /// context. If a guard can be leaked, e.g. by [`std::mem::forget`], the
+    /// handler must borrow nothing but data outliving the context.
+    pub(crate) unsafe fn new_unchecked(
+        context: &'c Context,
+        mut handler: impl FnMut(Diagnostic<'c>) -> bool + 'c,
+    ) -> Self {
+        Self {
+            context,
+            id: context.attach_diagnostic_handler(move |diagnostic| {
+                handler(unsafe { Diagnostic::from_raw(diagnostic.raw) })
+            }),
+        }
+    }
+
+    /// Returns a handler ID.
+    pub const fn id(&self) -> DiagnosticHandlerId {
+        self.id
+    }
+}
+
+impl Drop for DiagnosticHandlerGuard<'_> {
+    fn drop(&mut self) {
+        self.context.detach_diagnostic_handler(self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::Module;
+    use std::{cell::Cell, rc::Rc};
+
+    #[test]
+    fn detach_on_drop() {
+        let context = Context::new();
+        let count = Rc::new(Cell::new(0));
+
+        {
+            let _guard = DiagnosticHandlerGuard::new(&context, {
+                let count = count.clone();
+
+                move |_| {
+                    count.set(count.get() + 1);
+                    true
+                }
+            });
+
+            Module::parse(&context, "foo");
+        }
+
+        Module::parse(&context, "foo");
+
+        assert_eq!(count.get(), 1);
+    }
+}
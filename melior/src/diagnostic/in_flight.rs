@@ -0,0 +1,109 @@
+use crate::ir::Location;
+use mlir_sys::mlirEmitError;
+use std::{ffi::CString, fmt::Display};
+
+/// An error diagnostic being built before emission.
+///
+/// A diagnostic is emitted to diagnostic handlers attached to a context when
+/// [`emit`](Self::emit) is called or it is dropped.
+///
+/// Only errors without notes are supported as the C API cannot emit
+/// warnings, remarks, or notes.
+#[must_use = "diagnostics are emitted on drop"]
+#[derive(Debug)]
+pub struct InFlightDiagnostic<'c> {
+    location: Location<'c>,
+    message: String,
+    active: bool,
+}
+
+impl<'c> InFlightDiagnostic<'c> {
+    /// Creates an in-flight error diagnostic.
+    pub fn new(location: Location<'c>, message: impl Display) -> Self {
+        Self {
+            location,
+            message: message.to_string(),
+            active: true,
+        }
+    }
+
+    /// Appends a message.
+    pub fn append(mut self, message: impl Display) -> Self {
+        self.message += &message.to_string();
+        self
+    }
+
+    /// Emits a diagnostic.
+    pub fn emit(mut self) {
+        self.report();
+    }
+
+    /// Abandons a diagnostic without emitting it.
+    pub fn abandon(mut self) {
+        self.active = false;
+    }
+
+    fn report(&mut self) {
+        if !self.active {
+            return;
+        }
+
+        self.active = false;
+
+        // Null characters cannot be passed to the C API.
+        let message = CString::new(self.message.replace('\0', "")).expect("valid C string");
+
+        unsafe { mlirEmitError(self.location.to_raw(), message.as_ptr()) }
+    }
+}
+
+impl Drop for InFlightDiagnostic<'_> {
+    fn drop(&mut self) {
+        self.report();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Context,
+        diagnostic::{DiagnosticCollector, DiagnosticSeverity},
+    };
+
+    #[test]
+    fn emit() {
+        let context = Context::new();
+        let location = Location::new(&context, "foo.mlir", 1, 2);
+        let collector = DiagnosticCollector::new(&context);
+
+        InFlightDiagnostic::new(location, "foo").append(42).emit();
+
+        let diagnostics = collector.take();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message(), "foo42");
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+        assert_eq!(diagnostics[0].location(), location);
+    }
+
+    #[test]
+    fn emit_on_drop() {
+        let context = Context::new();
+        let collector = DiagnosticCollector::new(&context);
+
+        drop(InFlightDiagnostic::new(Location::unknown(&context), "foo"));
+
+        assert_eq!(collector.take().len(), 1);
+    }
+
+    #[test]
+    fn abandon() {
+        let context = Context::new();
+        let collector = DiagnosticCollector::new(&context);
+
+        InFlightDiagnostic::new(Location::unknown(&context), "foo").abandon();
+
+        assert!(collector.take().is_empty());
+    }
+}
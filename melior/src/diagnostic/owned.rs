@@ -0,0 +1,120 @@
+use super::{Diagnostic, DiagnosticSeverity};
+use crate::ir::Location;
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+
+/// A diagnostic owned by Rust.
+///
+/// Unlike [`Diagnostic`], which is valid only during a call of a diagnostic
+/// handler, owned diagnostics can be stored and inspected later.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnedDiagnostic<'c> {
+    message: String,
+    severity: DiagnosticSeverity,
+    location: Location<'c>,
+    notes: Vec<OwnedDiagnostic<'c>>,
+}
+
+impl<'c> OwnedDiagnostic<'c> {
+    /// Creates a diagnostic.
+    pub fn new(
+        message: impl Into<String>,
+        severity: DiagnosticSeverity,
+        location: Location<'c>,
+        notes: Vec<Self>,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            severity,
+            location,
+            notes,
+        }
+    }
+
+    /// Returns a message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns a severity.
+    pub const fn severity(&self) -> DiagnosticSeverity {
+        self.severity
+    }
+
+    /// Returns a location.
+    pub const fn location(&self) -> Location<'c> {
+        self.location
+    }
+
+    /// Returns notes.
+    pub fn notes(&self) -> &[Self] {
+        &self.notes
+    }
+
+    /// Renders a diagnostic and its notes with source code excerpts at their
+    /// locations.
+    ///
+    /// `sources` is a map from filenames to their contents.
+    pub fn render(&self, sources: &HashMap<String, String>) -> String {
+        let mut string = format!("{}: {}\n", self.severity, self.message);
+
+        if let Some(snippet) = self.location.render_snippet(sources) {
+            string += &snippet;
+        }
+
+        for note in &self.notes {
+            string += &note.render(sources);
+        }
+
+        string
+    }
+}
+
+impl<'c> From<&Diagnostic<'c>> for OwnedDiagnostic<'c> {
+    fn from(diagnostic: &Diagnostic<'c>) -> Self {
+        Self {
+            message: diagnostic.to_string(),
+            severity: diagnostic.severity(),
+            location: diagnostic.location(),
+            notes: (0..diagnostic.note_count())
+                .map(|index| Self::from(&diagnostic.note(index).expect("valid note index")))
+                .collect(),
+        }
+    }
+}
+
+impl Display for OwnedDiagnostic<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn render() {
+        let context = Context::new();
+        let sources = HashMap::from([("foo.x".to_owned(), "let x = 1;\n".to_owned())]);
+        let diagnostic = OwnedDiagnostic::new(
+            "foo",
+            DiagnosticSeverity::Error,
+            Location::file_line_col_range(&context, "foo.x", 1, 5, 1, 6),
+            vec![OwnedDiagnostic::new(
+                "bar",
+                DiagnosticSeverity::Note,
+                Location::unknown(&context),
+                vec![],
+            )],
+        );
+
+        assert_eq!(
+            diagnostic.render(&sources),
+            "error: foo\n --> foo.x:1:5\n  |\n1 | let x = 1;\n  |     ^\nnote: bar\n"
+        );
+    }
+}
@@ -3,9 +3,10 @@ use mlir_sys::{
     MlirDiagnosticSeverity_MlirDiagnosticError, MlirDiagnosticSeverity_MlirDiagnosticNote,
     MlirDiagnosticSeverity_MlirDiagnosticRemark, MlirDiagnosticSeverity_MlirDiagnosticWarning,
 };
+use std::fmt::{self, Display, Formatter};
 
 /// Diagnostic severity.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum DiagnosticSeverity {
     Error,
     Note,
@@ -27,3 +28,18 @@ impl TryFrom<u32> for DiagnosticSeverity {
         })
     }
 }
+
+impl Display for DiagnosticSeverity {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{}",
+            match self {
+                Self::Error => "error",
+                Self::Note => "note",
+                Self::Remark => "remark",
+                Self::Warning => "warning",
+            }
+        )
+    }
+}
@@ -3,9 +3,14 @@
 use crate::{
     Context,
     ir::{
-        Attribute, Identifier, Location, Operation, Region, Type, Value,
-        attribute::{FlatSymbolRefAttribute, StringAttribute, TypeAttribute},
+        Attribute, Block, BlockRef, Identifier, Location, Operation, Region, RegionLike, Type,
+        Value,
+        attribute::{
+            ArrayAttribute, DictionaryAttribute, FlatSymbolRefAttribute, StringAttribute,
+            TypeAttribute,
+        },
         operation::OperationBuilder,
+        symbol_table::SymbolVisibility,
         r#type::FunctionType,
     },
 };
@@ -75,6 +80,166 @@ pub fn func<'c>(
         .expect("valid operation")
 }
 
+/// A `func.func` operation builder.
+pub struct FuncBuilder<'c> {
+    context: &'c Context,
+    name: StringAttribute<'c>,
+    r#type: FunctionType<'c>,
+    argument_attributes: Vec<Vec<(Identifier<'c>, Attribute<'c>)>>,
+    result_attributes: Vec<Vec<(Identifier<'c>, Attribute<'c>)>>,
+    visibility: Option<SymbolVisibility>,
+    attributes: Vec<(Identifier<'c>, Attribute<'c>)>,
+    location: Location<'c>,
+}
+
+impl<'c> FuncBuilder<'c> {
+    /// Creates a `func.func` operation builder.
+    pub fn new(
+        context: &'c Context,
+        name: &str,
+        r#type: FunctionType<'c>,
+        location: Location<'c>,
+    ) -> Self {
+        Self {
+            context,
+            name: StringAttribute::new(context, name),
+            r#type,
+            argument_attributes: vec![vec![]; r#type.input_count()],
+            result_attributes: vec![vec![]; r#type.result_count()],
+            visibility: None,
+            attributes: vec![],
+            location,
+        }
+    }
+
+    /// Adds attributes of an argument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn add_argument_attributes(
+        mut self,
+        index: usize,
+        attributes: &[(Identifier<'c>, Attribute<'c>)],
+    ) -> Self {
+        self.argument_attributes[index].extend_from_slice(attributes);
+        self
+    }
+
+    /// Adds attributes of a result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn add_result_attributes(
+        mut self,
+        index: usize,
+        attributes: &[(Identifier<'c>, Attribute<'c>)],
+    ) -> Self {
+        self.result_attributes[index].extend_from_slice(attributes);
+        self
+    }
+
+    /// Sets a visibility.
+    pub fn visibility(mut self, visibility: SymbolVisibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Adds the `llvm.emit_c_interface` attribute.
+    pub fn emit_c_interface(self) -> Self {
+        let attribute = (
+            Identifier::new(self.context, "llvm.emit_c_interface"),
+            Attribute::unit(self.context),
+        );
+
+        self.add_attributes(&[attribute])
+    }
+
+    /// Adds attributes.
+    pub fn add_attributes(mut self, attributes: &[(Identifier<'c>, Attribute<'c>)]) -> Self {
+        self.attributes.extend_from_slice(attributes);
+        self
+    }
+
+    /// Builds a function with a body.
+    ///
+    /// The closure receives an entry block with arguments of the function
+    /// type. It must insert terminators, e.g. `func.return`.
+    pub fn build(self, body: impl for<'b> FnOnce(BlockRef<'c, 'b>)) -> Operation<'c> {
+        let region = Region::new();
+        let block = region.append_block(Block::new(
+            &(0..self.r#type.input_count())
+                .map(|index| {
+                    (
+                        self.r#type.input(index).expect("valid input index"),
+                        self.location,
+                    )
+                })
+                .collect::<Vec<_>>(),
+        ));
+
+        body(block);
+
+        self.build_with_region(region, None)
+    }
+
+    /// Builds an external function declaration with no body.
+    ///
+    /// Its visibility is private unless set otherwise.
+    pub fn build_declaration(self) -> Operation<'c> {
+        self.build_with_region(Region::new(), Some(SymbolVisibility::Private))
+    }
+
+    fn build_with_region(
+        self,
+        region: Region<'c>,
+        default_visibility: Option<SymbolVisibility>,
+    ) -> Operation<'c> {
+        let context = self.context;
+        let mut attributes = vec![];
+
+        if let Some(visibility) = self.visibility.or(default_visibility) {
+            if visibility != SymbolVisibility::Public {
+                attributes.push((
+                    Identifier::new(context, "sym_visibility"),
+                    StringAttribute::new(context, visibility.as_str()).into(),
+                ));
+            }
+        }
+
+        for (name, dictionaries) in [
+            ("arg_attrs", &self.argument_attributes),
+            ("res_attrs", &self.result_attributes),
+        ] {
+            if dictionaries.iter().any(|attributes| !attributes.is_empty()) {
+                attributes.push((
+                    Identifier::new(context, name),
+                    ArrayAttribute::new(
+                        context,
+                        &dictionaries
+                            .iter()
+                            .map(|attributes| DictionaryAttribute::new(context, attributes).into())
+                            .collect::<Vec<_>>(),
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        attributes.extend(self.attributes);
+
+        func(
+            context,
+            self.name,
+            TypeAttribute::new(self.r#type.into()),
+            region,
+            &attributes,
+            self.location,
+        )
+    }
+}
+
 /// Create a `func.return` operation.
 pub fn r#return<'c>(operands: &[Value<'c, '_>], location: Location<'c>) -> Operation<'c> {
     OperationBuilder::new("func.return", location)
@@ -245,4 +410,74 @@ mod tests {
         assert!(module.as_operation().verify());
         insta::assert_snapshot!(module.as_operation());
     }
+
+    #[test]
+    fn build_function() {
+        let context = create_test_context();
+
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+        let index_type = Type::index(&context);
+
+        let function = FuncBuilder::new(
+            &context,
+            "foo",
+            FunctionType::new(&context, &[index_type, index_type], &[index_type]),
+            location,
+        )
+        .add_argument_attributes(
+            1,
+            &[(
+                Identifier::new(&context, "foo.bar"),
+                Attribute::unit(&context),
+            )],
+        )
+        .visibility(SymbolVisibility::Private)
+        .emit_c_interface()
+        .build(|block| {
+            block.append_operation(r#return(&[block.argument(1).unwrap().into()], location));
+        });
+
+        assert_eq!(
+            function.attribute("sym_visibility").unwrap(),
+            StringAttribute::new(&context, "private").into()
+        );
+        assert_eq!(
+            function.attribute("arg_attrs").unwrap(),
+            Attribute::parse(&context, "[{}, {foo.bar}]").unwrap()
+        );
+        assert!(function.has_attribute("llvm.emit_c_interface"));
+        assert!(!function.has_attribute("res_attrs"));
+
+        module.body().append_operation(function);
+
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn build_declaration() {
+        let context = create_test_context();
+
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+        let index_type = Type::index(&context);
+
+        let function = FuncBuilder::new(
+            &context,
+            "foo",
+            FunctionType::new(&context, &[index_type], &[index_type]),
+            location,
+        )
+        .build_declaration();
+
+        assert_eq!(
+            function.attribute("sym_visibility").unwrap(),
+            StringAttribute::new(&context, "private").into()
+        );
+        assert_eq!(function.region(0).unwrap().first_block(), None);
+
+        module.body().append_operation(function);
+
+        assert!(module.as_operation().verify());
+    }
 }
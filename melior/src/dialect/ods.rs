@@ -201,7 +201,7 @@ mod tests {
     use crate::{
         Context, dialect,
         ir::{
-            Block, BlockLike, Location, Module, Region, RegionLike, Type,
+            Block, BlockLike, Identifier, Location, Module, Operation, Region, RegionLike, Type,
             attribute::{IntegerAttribute, StringAttribute, TypeAttribute},
             operation::OperationLike,
             r#type::{FunctionType, IntegerType},
@@ -380,4 +380,41 @@ mod tests {
             block.append_operation(func::r#return(&context, &[], location).into());
         });
     }
+
+    #[test]
+    fn downcast_operation() {
+        let context = create_test_context();
+        let operation = Operation::from(func::r#return(&context, &[], Location::unknown(&context)));
+
+        assert!(operation.isa::<func::ReturnOperation>());
+        assert!(!operation.isa::<arith::AddFOperation>());
+        assert!(
+            operation
+                .clone()
+                .dyn_cast::<arith::AddFOperation>()
+                .is_err()
+        );
+        assert_eq!(
+            operation
+                .cast::<func::ReturnOperation>()
+                .as_operation()
+                .name(),
+            Identifier::new(&context, "func.return")
+        );
+    }
+
+    #[test]
+    fn downcast_operation_ref() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+        let operation = block.append_operation(func::r#return(&context, &[], location).into());
+
+        assert!(operation.isa::<func::ReturnOperation>());
+        assert!(operation.dyn_cast::<&arith::AddFOperation>().is_none());
+        assert_eq!(
+            operation.cast::<&func::ReturnOperation>().as_operation(),
+            &*operation
+        );
+    }
 }
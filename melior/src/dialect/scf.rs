@@ -1,10 +1,14 @@
 //! `scf` dialect.
+//!
+//! The `build_*` functions build regions with closures. The closures can
+//! yield values defined in their blocks as well as values defined outside of
+//! operations being built.
 
 use crate::{
     Context,
     ir::{
-        Identifier, Location, Operation, Region, Type, Value, attribute::DenseI64ArrayAttribute,
-        operation::OperationBuilder,
+        Block, BlockLike, Identifier, Location, Operation, Region, RegionLike, Type, Value,
+        ValueLike, attribute::DenseI64ArrayAttribute, operation::OperationBuilder,
     },
 };
 
@@ -107,6 +111,170 @@ pub fn r#yield<'c>(values: &[Value<'c, '_>], location: Location<'c>) -> Operatio
         .expect("valid operation")
 }
 
+/// Creates a `scf.execute_region` operation with a body built by a closure.
+///
+/// The closure receives a body block and returns values to yield.
+pub fn build_execute_region<'c: 'a, 'a>(
+    result_types: &[Type<'c>],
+    body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+    location: Location<'c>,
+) -> Operation<'c> {
+    execute_region(
+        result_types,
+        build_region(&[], |block| r#yield(&body(block), location)),
+        location,
+    )
+}
+
+/// Creates a `scf.for` operation with a body built by a closure.
+///
+/// The closure receives a body block, an induction variable, and iteration
+/// arguments initialized with `initial_values`, and returns values to yield.
+/// Results of the operation are final values of the iteration arguments.
+pub fn build_for<'c: 'a, 'a>(
+    start: Value<'c, '_>,
+    end: Value<'c, '_>,
+    step: Value<'c, '_>,
+    initial_values: &[Value<'c, '_>],
+    body: impl for<'b> FnOnce(&'b Block<'a>, Value<'a, 'b>, &[Value<'a, 'b>]) -> Vec<Value<'a, 'b>>,
+    location: Location<'c>,
+) -> Operation<'c> {
+    let types = value_types(initial_values);
+
+    OperationBuilder::new("scf.for", location)
+        .add_operands(&[start, end, step])
+        .add_operands(initial_values)
+        .add_results(&types)
+        .add_regions([build_region(
+            &[start.r#type()]
+                .into_iter()
+                .chain(types.iter().copied())
+                .map(|r#type| (r#type, location))
+                .collect::<Vec<_>>(),
+            |block| {
+                let arguments = block_arguments(block);
+
+                r#yield(&body(block, arguments[0], &arguments[1..]), location)
+            },
+        )])
+        .build()
+        .expect("valid operation")
+}
+
+/// Creates a `scf.if` operation with branches built by closures.
+///
+/// The closures receive branch blocks and return values to yield.
+pub fn build_if<'c: 'a, 'a>(
+    condition: Value<'c, '_>,
+    result_types: &[Type<'c>],
+    then_body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+    else_body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+    location: Location<'c>,
+) -> Operation<'c> {
+    r#if(
+        condition,
+        result_types,
+        build_region(&[], |block| r#yield(&then_body(block), location)),
+        build_region(&[], |block| r#yield(&else_body(block), location)),
+        location,
+    )
+}
+
+/// Creates a `scf.index_switch` operation with regions built by closures.
+///
+/// The closures receive region blocks and return values to yield. `case_body`
+/// also receives a case value.
+pub fn build_index_switch<'c: 'a, 'a>(
+    context: &'c Context,
+    condition: Value<'c, '_>,
+    result_types: &[Type<'c>],
+    cases: &[i64],
+    default_body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+    mut case_body: impl for<'b> FnMut(&'b Block<'a>, i64) -> Vec<Value<'a, 'b>>,
+    location: Location<'c>,
+) -> Operation<'c> {
+    index_switch(
+        context,
+        condition,
+        result_types,
+        DenseI64ArrayAttribute::new(context, cases),
+        [build_region(&[], |block| {
+            r#yield(&default_body(block), location)
+        })]
+        .into_iter()
+        .chain(
+            cases
+                .iter()
+                .map(|&case| build_region(&[], |block| r#yield(&case_body(block, case), location))),
+        )
+        .collect(),
+        location,
+    )
+}
+
+/// Creates a `scf.while` operation with regions built by closures.
+///
+/// `before_body` receives a block with arguments initialized with
+/// `initial_values`, and returns a condition and values passed to
+/// `after_body` or returned as results. `after_body` receives a block with
+/// arguments of `result_types`, and returns values passed back to
+/// `before_body`.
+pub fn build_while<'c: 'a, 'a>(
+    initial_values: &[Value<'c, '_>],
+    result_types: &[Type<'c>],
+    before_body: impl for<'b> FnOnce(
+        &'b Block<'a>,
+        &[Value<'a, 'b>],
+    ) -> (Value<'a, 'b>, Vec<Value<'a, 'b>>),
+    after_body: impl for<'b> FnOnce(&'b Block<'a>, &[Value<'a, 'b>]) -> Vec<Value<'a, 'b>>,
+    location: Location<'c>,
+) -> Operation<'c> {
+    r#while(
+        initial_values,
+        result_types,
+        build_region(
+            &value_types(initial_values)
+                .into_iter()
+                .map(|r#type| (r#type, location))
+                .collect::<Vec<_>>(),
+            |block| {
+                let (value, values) = before_body(block, &block_arguments(block));
+
+                self::condition(value, &values, location)
+            },
+        ),
+        build_region(
+            &result_types
+                .iter()
+                .map(|&r#type| (r#type, location))
+                .collect::<Vec<_>>(),
+            |block| r#yield(&after_body(block, &block_arguments(block)), location),
+        ),
+        location,
+    )
+}
+
+fn build_region<'c: 'a, 'a>(
+    arguments: &[(Type<'c>, Location<'c>)],
+    build: impl for<'b> FnOnce(&'b Block<'a>) -> Operation<'a>,
+) -> Region<'c> {
+    let block = Block::new(arguments);
+    let reference: &Block<'a> = &block;
+    reference.append_operation(build(reference));
+
+    let region = Region::new();
+    region.append_block(block);
+    region
+}
+
+fn block_arguments<'c, 'b>(block: &'b Block<'c>) -> Vec<Value<'c, 'b>> {
+    block.arguments().map(Into::into).collect()
+}
+
+fn value_types<'c>(values: &[Value<'c, '_>]) -> Vec<Type<'c>> {
+    values.iter().map(|value| value.r#type()).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -719,4 +887,243 @@ mod tests {
             insta::assert_snapshot!(module.as_operation());
         }
     }
+
+    mod build {
+        use super::*;
+
+        fn compile<'c>(
+            context: &'c Context,
+            build: impl for<'b> FnOnce(&'b Block<'c>) -> Vec<Value<'c, 'b>>,
+        ) {
+            let location = Location::unknown(context);
+            let module = Module::new(location);
+            let index_type = Type::index(context);
+
+            module.body().append_operation(func::func(
+                context,
+                StringAttribute::new(context, "foo"),
+                TypeAttribute::new(FunctionType::new(context, &[], &[index_type]).into()),
+                {
+                    let block = Block::new(&[]);
+                    let values = build(&block);
+                    block.append_operation(func::r#return(&values, location));
+
+                    let region = Region::new();
+                    region.append_block(block);
+                    region
+                },
+                &[],
+                location,
+            ));
+
+            assert!(module.as_operation().verify());
+        }
+
+        fn constant<'c, 'b>(
+            context: &'c Context,
+            block: &'b Block<'c>,
+            value: i64,
+        ) -> Value<'c, 'b> {
+            block
+                .append_operation(arith::constant(
+                    context,
+                    IntegerAttribute::new(Type::index(context), value).into(),
+                    Location::unknown(context),
+                ))
+                .result(0)
+                .unwrap()
+                .into()
+        }
+
+        #[test]
+        fn execute_region() {
+            let context = Context::new();
+            load_all_dialects(&context);
+            let location = Location::unknown(&context);
+
+            compile(&context, |block| {
+                block
+                    .append_operation(build_execute_region(
+                        &[Type::index(&context)],
+                        |block| vec![constant(&context, block, 42)],
+                        location,
+                    ))
+                    .results()
+                    .map(Into::into)
+                    .collect()
+            });
+        }
+
+        #[test]
+        fn r#for() {
+            let context = Context::new();
+            load_all_dialects(&context);
+            let location = Location::unknown(&context);
+
+            compile(&context, |block| {
+                let start = constant(&context, block, 0);
+                let end = constant(&context, block, 8);
+                let step = constant(&context, block, 1);
+
+                block
+                    .append_operation(build_for(
+                        start,
+                        end,
+                        step,
+                        &[start],
+                        |block, index, arguments| {
+                            vec![
+                                block
+                                    .append_operation(arith::addi(arguments[0], index, location))
+                                    .result(0)
+                                    .unwrap()
+                                    .into(),
+                            ]
+                        },
+                        location,
+                    ))
+                    .results()
+                    .map(Into::into)
+                    .collect()
+            });
+        }
+
+        #[test]
+        fn r#if() {
+            let context = Context::new();
+            load_all_dialects(&context);
+            let location = Location::unknown(&context);
+
+            compile(&context, |block| {
+                let condition = block
+                    .append_operation(arith::constant(
+                        &context,
+                        IntegerAttribute::new(IntegerType::new(&context, 1).into(), 0).into(),
+                        location,
+                    ))
+                    .result(0)
+                    .unwrap()
+                    .into();
+
+                block
+                    .append_operation(build_if(
+                        condition,
+                        &[Type::index(&context)],
+                        |block| vec![constant(&context, block, 1)],
+                        |block| vec![constant(&context, block, 2)],
+                        location,
+                    ))
+                    .results()
+                    .map(Into::into)
+                    .collect()
+            });
+        }
+
+        #[test]
+        fn if_with_outer_values() {
+            let context = Context::new();
+            load_all_dialects(&context);
+            let location = Location::unknown(&context);
+
+            compile(&context, |block| {
+                let condition = block
+                    .append_operation(arith::constant(
+                        &context,
+                        IntegerAttribute::new(IntegerType::new(&context, 1).into(), 0).into(),
+                        location,
+                    ))
+                    .result(0)
+                    .unwrap()
+                    .into();
+                let one = constant(&context, block, 1);
+                let two = constant(&context, block, 2);
+
+                block
+                    .append_operation(build_if(
+                        condition,
+                        &[Type::index(&context)],
+                        |_| vec![one],
+                        |_| vec![two],
+                        location,
+                    ))
+                    .results()
+                    .map(Into::into)
+                    .collect()
+            });
+        }
+
+        #[test]
+        fn index_switch() {
+            let context = Context::new();
+            load_all_dialects(&context);
+            let location = Location::unknown(&context);
+
+            compile(&context, |block| {
+                let condition = constant(&context, block, 1);
+
+                block
+                    .append_operation(build_index_switch(
+                        &context,
+                        condition,
+                        &[Type::index(&context)],
+                        &[0, 1],
+                        |block| vec![constant(&context, block, -1)],
+                        |block, case| vec![constant(&context, block, case * 2)],
+                        location,
+                    ))
+                    .results()
+                    .map(Into::into)
+                    .collect()
+            });
+        }
+
+        #[test]
+        fn r#while() {
+            let context = Context::new();
+            load_all_dialects(&context);
+            let location = Location::unknown(&context);
+            let index_type = Type::index(&context);
+
+            compile(&context, |block| {
+                let initial = constant(&context, block, 0);
+
+                block
+                    .append_operation(build_while(
+                        &[initial],
+                        &[index_type],
+                        |block, arguments| {
+                            let limit = constant(&context, block, 8);
+                            let condition = block
+                                .append_operation(arith::cmpi(
+                                    &context,
+                                    arith::CmpiPredicate::Slt,
+                                    arguments[0],
+                                    limit,
+                                    location,
+                                ))
+                                .result(0)
+                                .unwrap()
+                                .into();
+
+                            (condition, arguments.to_vec())
+                        },
+                        |block, arguments| {
+                            let one = constant(&context, block, 1);
+
+                            vec![
+                                block
+                                    .append_operation(arith::addi(arguments[0], one, location))
+                                    .result(0)
+                                    .unwrap()
+                                    .into(),
+                            ]
+                        },
+                        location,
+                    ))
+                    .results()
+                    .map(Into::into)
+                    .collect()
+            });
+        }
+    }
 }
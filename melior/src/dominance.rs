@@ -0,0 +1,855 @@
+//! Dominance analysis.
+//!
+//! Dominance information is computed in Rust from the IR exposed by the MLIR C
+//! API and is scoped to regions nested in an operation. Queries about IR
+//! outside that operation report no dominance.
+//!
+//! # Limitations
+//!
+//! - All regions are assumed to have SSA dominance. Graph regions, where
+//!   operations can use values defined later, are not handled.
+//! - Dominator trees are cached per region on their first query and never
+//!   invalidated. Create new dominance information after modifying IR.
+
+use crate::ir::{BlockLike, BlockRef, OperationRef, Value, ValueLike};
+use mlir_sys::{
+    MlirBlock, MlirOperation, MlirRegion, mlirBlockArgumentGetOwner, mlirBlockGetNextInRegion,
+    mlirBlockGetNumSuccessors, mlirBlockGetParentOperation, mlirBlockGetParentRegion,
+    mlirBlockGetSuccessor, mlirOpResultGetOwner, mlirOperationGetBlock,
+    mlirOperationIsBeforeInBlock, mlirRegionGetFirstBlock,
+};
+use std::{cell::RefCell, collections::HashMap, ffi::c_void, marker::PhantomData, rc::Rc};
+
+/// Dominance information of blocks and operations.
+///
+/// Dominator trees are computed lazily for each region on the first query
+/// and are not updated on IR modification. All regions are assumed to have
+/// SSA dominance.
+#[derive(Debug)]
+pub struct DominanceInfo<'c, 'a> {
+    base: DominanceInfoBase<false>,
+    _operation: PhantomData<OperationRef<'c, 'a>>,
+}
+
+impl<'c, 'a> DominanceInfo<'c, 'a> {
+    /// Creates dominance information of regions nested in an operation.
+    pub fn new(operation: OperationRef<'c, 'a>) -> Self {
+        Self {
+            base: DominanceInfoBase::new(operation.to_raw()),
+            _operation: Default::default(),
+        }
+    }
+
+    /// Returns `true` if an operation dominates another.
+    pub fn dominates(&self, one: OperationRef<'c, '_>, other: OperationRef<'c, '_>) -> bool {
+        one == other || self.properly_dominates(one, other)
+    }
+
+    /// Returns `true` if an operation properly dominates another.
+    ///
+    /// An operation properly dominates operations nested in its regions.
+    pub fn properly_dominates(
+        &self,
+        one: OperationRef<'c, '_>,
+        other: OperationRef<'c, '_>,
+    ) -> bool {
+        self.base
+            .properly_dominates_operation(one.to_raw(), other.to_raw(), true)
+    }
+
+    /// Returns `true` if a value dominates an operation.
+    ///
+    /// An operation defining a value is dominated by it.
+    pub fn dominates_value(
+        &self,
+        value: impl ValueLike<'c>,
+        operation: OperationRef<'c, '_>,
+    ) -> bool {
+        let value = unsafe { Value::from_raw(value.to_raw()) };
+
+        (!value.is_block_argument()
+            && unsafe { mlirOpResultGetOwner(value.to_raw()) }.ptr == operation.to_raw().ptr)
+            || self.properly_dominates_value(value, operation)
+    }
+
+    /// Returns `true` if a value properly dominates an operation, which means
+    /// that the operation can use the value.
+    pub fn properly_dominates_value(
+        &self,
+        value: impl ValueLike<'c>,
+        operation: OperationRef<'c, '_>,
+    ) -> bool {
+        if value.is_block_argument() {
+            let block = unsafe { mlirOperationGetBlock(operation.to_raw()) };
+
+            !block.ptr.is_null()
+                && self
+                    .base
+                    .dominates_block(unsafe { mlirBlockArgumentGetOwner(value.to_raw()) }, block)
+        } else {
+            self.base.properly_dominates_operation(
+                unsafe { mlirOpResultGetOwner(value.to_raw()) },
+                operation.to_raw(),
+                false,
+            )
+        }
+    }
+
+    /// Returns `true` if a block dominates another.
+    pub fn dominates_block(&self, one: BlockRef<'c, '_>, other: BlockRef<'c, '_>) -> bool {
+        self.base.dominates_block(one.to_raw(), other.to_raw())
+    }
+
+    /// Returns `true` if a block properly dominates another.
+    pub fn properly_dominates_block(&self, one: BlockRef<'c, '_>, other: BlockRef<'c, '_>) -> bool {
+        self.base
+            .properly_dominates_block(one.to_raw(), other.to_raw())
+    }
+
+    /// Returns the nearest common dominator of two blocks.
+    pub fn nearest_common_dominator(
+        &self,
+        one: BlockRef<'c, 'a>,
+        other: BlockRef<'c, 'a>,
+    ) -> Option<BlockRef<'c, 'a>> {
+        self.base
+            .nearest_common_dominator(one.to_raw(), other.to_raw())
+            .map(|block| unsafe { BlockRef::from_raw(block) })
+    }
+
+    /// Returns the immediate dominator of a block.
+    pub fn immediate_dominator(&self, block: BlockRef<'c, 'a>) -> Option<BlockRef<'c, 'a>> {
+        self.base
+            .immediate_dominator(block.to_raw())
+            .map(|block| unsafe { BlockRef::from_raw(block) })
+    }
+
+    /// Returns blocks immediately dominated by a block in a dominator tree.
+    pub fn children(&self, block: BlockRef<'c, 'a>) -> impl Iterator<Item = BlockRef<'c, 'a>> {
+        self.base
+            .children(block.to_raw())
+            .into_iter()
+            .map(|block| unsafe { BlockRef::from_raw(block) })
+    }
+
+    /// Returns `true` if a block is reachable from the entry block of its
+    /// region.
+    pub fn is_reachable_from_entry(&self, block: BlockRef<'c, '_>) -> bool {
+        self.base.is_reachable(block.to_raw())
+    }
+}
+
+/// Post-dominance information of blocks and operations.
+///
+/// Post-dominator trees are computed lazily for each region on the first
+/// query and are not updated on IR modification. All regions are assumed to
+/// have SSA dominance.
+#[derive(Debug)]
+pub struct PostDominanceInfo<'c, 'a> {
+    base: DominanceInfoBase<true>,
+    _operation: PhantomData<OperationRef<'c, 'a>>,
+}
+
+impl<'c, 'a> PostDominanceInfo<'c, 'a> {
+    /// Creates post-dominance information of regions nested in an operation.
+    pub fn new(operation: OperationRef<'c, 'a>) -> Self {
+        Self {
+            base: DominanceInfoBase::new(operation.to_raw()),
+            _operation: Default::default(),
+        }
+    }
+
+    /// Returns `true` if an operation post-dominates another.
+    pub fn post_dominates(&self, one: OperationRef<'c, '_>, other: OperationRef<'c, '_>) -> bool {
+        one == other || self.properly_post_dominates(one, other)
+    }
+
+    /// Returns `true` if an operation properly post-dominates another.
+    ///
+    /// An operation properly post-dominates operations nested in its regions.
+    pub fn properly_post_dominates(
+        &self,
+        one: OperationRef<'c, '_>,
+        other: OperationRef<'c, '_>,
+    ) -> bool {
+        self.base
+            .properly_dominates_operation(one.to_raw(), other.to_raw(), true)
+    }
+
+    /// Returns `true` if a block post-dominates another.
+    pub fn post_dominates_block(&self, one: BlockRef<'c, '_>, other: BlockRef<'c, '_>) -> bool {
+        self.base.dominates_block(one.to_raw(), other.to_raw())
+    }
+
+    /// Returns `true` if a block properly post-dominates another.
+    pub fn properly_post_dominates_block(
+        &self,
+        one: BlockRef<'c, '_>,
+        other: BlockRef<'c, '_>,
+    ) -> bool {
+        self.base
+            .properly_dominates_block(one.to_raw(), other.to_raw())
+    }
+
+    /// Returns the nearest common post-dominator of two blocks.
+    ///
+    /// Returns `None` if the blocks do not have any common post-dominator
+    /// block, such as when they reach different exit blocks.
+    pub fn nearest_common_post_dominator(
+        &self,
+        one: BlockRef<'c, 'a>,
+        other: BlockRef<'c, 'a>,
+    ) -> Option<BlockRef<'c, 'a>> {
+        self.base
+            .nearest_common_dominator(one.to_raw(), other.to_raw())
+            .map(|block| unsafe { BlockRef::from_raw(block) })
+    }
+
+    /// Returns the immediate post-dominator of a block.
+    pub fn immediate_post_dominator(&self, block: BlockRef<'c, 'a>) -> Option<BlockRef<'c, 'a>> {
+        self.base
+            .immediate_dominator(block.to_raw())
+            .map(|block| unsafe { BlockRef::from_raw(block) })
+    }
+
+    /// Returns blocks immediately post-dominated by a block in a
+    /// post-dominator tree.
+    pub fn children(&self, block: BlockRef<'c, 'a>) -> impl Iterator<Item = BlockRef<'c, 'a>> {
+        self.base
+            .children(block.to_raw())
+            .into_iter()
+            .map(|block| unsafe { BlockRef::from_raw(block) })
+    }
+}
+
+#[derive(Debug)]
+struct DominanceInfoBase<const POST: bool> {
+    operation: MlirOperation,
+    trees: RefCell<HashMap<*const c_void, Rc<DominatorTree>>>,
+}
+
+impl<const POST: bool> DominanceInfoBase<POST> {
+    fn new(operation: MlirOperation) -> Self {
+        Self {
+            operation,
+            trees: Default::default(),
+        }
+    }
+
+    /// Returns `true` if a block is nested in the analyzed operation.
+    fn contains_block(&self, mut block: MlirBlock) -> bool {
+        loop {
+            let operation = unsafe { mlirBlockGetParentOperation(block) };
+
+            if operation.ptr.is_null() {
+                return false;
+            } else if operation.ptr == self.operation.ptr {
+                return true;
+            }
+
+            block = unsafe { mlirOperationGetBlock(operation) };
+
+            if block.ptr.is_null() {
+                return false;
+            }
+        }
+    }
+
+    fn tree(&self, region: MlirRegion) -> Rc<DominatorTree> {
+        self.trees
+            .borrow_mut()
+            .entry(region.ptr as *const _)
+            .or_insert_with(|| Rc::new(DominatorTree::new(region, POST)))
+            .clone()
+    }
+
+    fn properly_dominates_operation(
+        &self,
+        one: MlirOperation,
+        mut other: MlirOperation,
+        enclosing: bool,
+    ) -> bool {
+        let block = unsafe { mlirOperationGetBlock(one) };
+        let mut other_block = unsafe { mlirOperationGetBlock(other) };
+
+        if block.ptr.is_null()
+            || other_block.ptr.is_null()
+            || one.ptr == other.ptr
+            || !self.contains_block(block)
+        {
+            return false;
+        }
+
+        let region = unsafe { mlirBlockGetParentRegion(block) };
+
+        if region.ptr != unsafe { mlirBlockGetParentRegion(other_block) }.ptr {
+            let Some(ancestor) = find_ancestor_operation_in_region(region, other) else {
+                return false;
+            };
+
+            other = ancestor;
+            other_block = unsafe { mlirOperationGetBlock(other) };
+
+            if one.ptr == other.ptr {
+                return enclosing;
+            }
+        }
+
+        if block.ptr == other_block.ptr {
+            unsafe {
+                if POST {
+                    mlirOperationIsBeforeInBlock(other, one)
+                } else {
+                    mlirOperationIsBeforeInBlock(one, other)
+                }
+            }
+        } else {
+            self.properly_dominates_block(block, other_block)
+        }
+    }
+
+    fn dominates_block(&self, one: MlirBlock, other: MlirBlock) -> bool {
+        (one.ptr == other.ptr && self.contains_block(one))
+            || self.properly_dominates_block(one, other)
+    }
+
+    fn properly_dominates_block(&self, one: MlirBlock, other: MlirBlock) -> bool {
+        if one.ptr == other.ptr || !self.contains_block(one) {
+            return false;
+        }
+
+        let region = unsafe { mlirBlockGetParentRegion(one) };
+
+        if region.ptr.is_null() {
+            return false;
+        }
+
+        let Some(other) = find_ancestor_block_in_region(region, other) else {
+            return false;
+        };
+
+        if one.ptr == other.ptr {
+            return true;
+        }
+
+        let tree = self.tree(region);
+
+        match (tree.index(one), tree.index(other)) {
+            (Some(one), Some(other)) => tree.properly_dominates(one, other),
+            _ => false,
+        }
+    }
+
+    fn nearest_common_dominator(&self, one: MlirBlock, other: MlirBlock) -> Option<MlirBlock> {
+        let (one, other) = find_ancestor_blocks_in_same_region(one, other)?;
+
+        if !self.contains_block(one) {
+            return None;
+        } else if one.ptr == other.ptr {
+            return Some(one);
+        }
+
+        let tree = self.tree(unsafe { mlirBlockGetParentRegion(one) });
+
+        tree.block(tree.nearest_common_dominator(tree.index(one)?, tree.index(other)?)?)
+    }
+
+    fn immediate_dominator(&self, block: MlirBlock) -> Option<MlirBlock> {
+        if !self.contains_block(block) {
+            return None;
+        }
+
+        let tree = self.tree(unsafe { mlirBlockGetParentRegion(block) });
+
+        tree.block(tree.immediate_dominators[tree.index(block)?]?)
+    }
+
+    fn children(&self, block: MlirBlock) -> Vec<MlirBlock> {
+        if !self.contains_block(block) {
+            return vec![];
+        }
+
+        let tree = self.tree(unsafe { mlirBlockGetParentRegion(block) });
+
+        tree.index(block)
+            .map(|index| {
+                tree.children[index]
+                    .iter()
+                    .filter_map(|&child| tree.block(child))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn is_reachable(&self, block: MlirBlock) -> bool {
+        if !self.contains_block(block) {
+            return false;
+        }
+
+        let tree = self.tree(unsafe { mlirBlockGetParentRegion(block) });
+
+        tree.index(block).is_some_and(|index| tree.reachable[index])
+    }
+}
+
+/// A dominator tree of blocks in a region.
+///
+/// A post-dominator tree has a virtual root node after all the blocks, which
+/// has exit blocks as its children.
+#[derive(Debug, Default)]
+struct DominatorTree {
+    blocks: Vec<MlirBlock>,
+    indices: HashMap<*const c_void, usize>,
+    immediate_dominators: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    levels: Vec<usize>,
+    reachable: Vec<bool>,
+}
+
+impl DominatorTree {
+    fn new(region: MlirRegion, post: bool) -> Self {
+        let mut blocks = vec![];
+
+        if !region.ptr.is_null() {
+            let mut block = unsafe { mlirRegionGetFirstBlock(region) };
+
+            while !block.ptr.is_null() {
+                blocks.push(block);
+                block = unsafe { mlirBlockGetNextInRegion(block) };
+            }
+        }
+
+        if blocks.is_empty() {
+            return Self::default();
+        }
+
+        let indices = blocks
+            .iter()
+            .enumerate()
+            .map(|(index, block)| (block.ptr as *const _, index))
+            .collect::<HashMap<_, _>>();
+        let mut successors = vec![vec![]; blocks.len()];
+        let mut predecessors = vec![vec![]; blocks.len()];
+
+        for (index, &block) in blocks.iter().enumerate() {
+            for successor_index in 0..unsafe { mlirBlockGetNumSuccessors(block) } {
+                let successor = unsafe { mlirBlockGetSuccessor(block, successor_index) };
+
+                if let Some(&successor) = indices.get(&(successor.ptr as *const _)) {
+                    successors[index].push(successor);
+                    predecessors[successor].push(index);
+                }
+            }
+        }
+
+        let (root, successors, predecessors) = if post {
+            let root = blocks.len();
+            let exits = (0..blocks.len())
+                .filter(|&index| successors[index].is_empty())
+                .collect::<Vec<_>>();
+            let mut reversed_predecessors = successors;
+
+            for &exit in &exits {
+                reversed_predecessors[exit].push(root);
+            }
+
+            reversed_predecessors.push(vec![]);
+            predecessors.push(exits);
+
+            (root, predecessors, reversed_predecessors)
+        } else {
+            (0, successors, predecessors)
+        };
+
+        let node_count = successors.len();
+        let mut reachable = vec![false; node_count];
+        let mut order = vec![];
+        let mut stack = vec![(root, 0)];
+
+        reachable[root] = true;
+
+        while let Some((node, index)) = stack.pop() {
+            if let Some(&successor) = successors[node].get(index) {
+                stack.push((node, index + 1));
+
+                if !reachable[successor] {
+                    reachable[successor] = true;
+                    stack.push((successor, 0));
+                }
+            } else {
+                order.push(node);
+            }
+        }
+
+        let mut numbers = vec![0; node_count];
+
+        for (number, &node) in order.iter().enumerate() {
+            numbers[node] = number;
+        }
+
+        // See "A Simple, Fast Dominance Algorithm" by Cooper, Harvey, and Kennedy.
+        let mut dominators = vec![None; node_count];
+        dominators[root] = Some(root);
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for &node in order.iter().rev().skip(1) {
+                let mut dominator = None;
+
+                for &predecessor in &predecessors[node] {
+                    if dominators[predecessor].is_none() {
+                        continue;
+                    }
+
+                    dominator = Some(match dominator {
+                        None => predecessor,
+                        Some(dominator) => {
+                            Self::intersect(&dominators, &numbers, predecessor, dominator)
+                        }
+                    });
+                }
+
+                if dominator != dominators[node] {
+                    dominators[node] = dominator;
+                    changed = true;
+                }
+            }
+        }
+
+        dominators[root] = None;
+
+        let mut children = vec![vec![]; node_count];
+        let mut levels = vec![0; node_count];
+
+        for &node in order.iter().rev() {
+            if let Some(dominator) = dominators[node] {
+                children[dominator].push(node);
+                levels[node] = levels[dominator] + 1;
+            }
+        }
+
+        Self {
+            blocks,
+            indices,
+            immediate_dominators: dominators,
+            children,
+            levels,
+            reachable,
+        }
+    }
+
+    fn intersect(
+        dominators: &[Option<usize>],
+        numbers: &[usize],
+        mut one: usize,
+        mut other: usize,
+    ) -> usize {
+        while one != other {
+            while numbers[one] < numbers[other] {
+                one = dominators[one].expect("dominator");
+            }
+
+            while numbers[other] < numbers[one] {
+                other = dominators[other].expect("dominator");
+            }
+        }
+
+        one
+    }
+
+    fn index(&self, block: MlirBlock) -> Option<usize> {
+        self.indices.get(&(block.ptr as *const _)).copied()
+    }
+
+    fn block(&self, index: usize) -> Option<MlirBlock> {
+        self.blocks.get(index).copied()
+    }
+
+    fn properly_dominates(&self, one: usize, other: usize) -> bool {
+        if one == other {
+            return false;
+        } else if !self.reachable[other] {
+            // Unreachable blocks are dominated by any block.
+            return true;
+        } else if !self.reachable[one] {
+            return false;
+        }
+
+        let mut node = other;
+
+        while let Some(dominator) = self.immediate_dominators[node] {
+            if dominator == one {
+                return true;
+            }
+
+            node = dominator;
+        }
+
+        false
+    }
+
+    fn nearest_common_dominator(&self, mut one: usize, mut other: usize) -> Option<usize> {
+        if !self.reachable[one] || !self.reachable[other] {
+            return None;
+        }
+
+        while self.levels[one] > self.levels[other] {
+            one = self.immediate_dominators[one]?;
+        }
+
+        while self.levels[other] > self.levels[one] {
+            other = self.immediate_dominators[other]?;
+        }
+
+        while one != other {
+            one = self.immediate_dominators[one]?;
+            other = self.immediate_dominators[other]?;
+        }
+
+        Some(one)
+    }
+}
+
+fn find_ancestor_operation_in_region(
+    region: MlirRegion,
+    mut operation: MlirOperation,
+) -> Option<MlirOperation> {
+    loop {
+        let block = unsafe { mlirOperationGetBlock(operation) };
+
+        if block.ptr.is_null() {
+            return None;
+        } else if unsafe { mlirBlockGetParentRegion(block) }.ptr == region.ptr {
+            return Some(operation);
+        }
+
+        operation = unsafe { mlirBlockGetParentOperation(block) };
+
+        if operation.ptr.is_null() {
+            return None;
+        }
+    }
+}
+
+fn find_ancestor_block_in_region(region: MlirRegion, mut block: MlirBlock) -> Option<MlirBlock> {
+    loop {
+        if unsafe { mlirBlockGetParentRegion(block) }.ptr == region.ptr {
+            return Some(block);
+        }
+
+        let operation = unsafe { mlirBlockGetParentOperation(block) };
+
+        if operation.ptr.is_null() {
+            return None;
+        }
+
+        block = unsafe { mlirOperationGetBlock(operation) };
+
+        if block.ptr.is_null() {
+            return None;
+        }
+    }
+}
+
+fn find_ancestor_blocks_in_same_region(
+    one: MlirBlock,
+    other: MlirBlock,
+) -> Option<(MlirBlock, MlirBlock)> {
+    let mut block = other;
+
+    loop {
+        let region = unsafe { mlirBlockGetParentRegion(block) };
+
+        if !region.ptr.is_null() {
+            if let Some(one) = find_ancestor_block_in_region(region, one) {
+                return Some((one, block));
+            }
+        }
+
+        let operation = unsafe { mlirBlockGetParentOperation(block) };
+
+        if operation.ptr.is_null() {
+            return None;
+        }
+
+        block = unsafe { mlirOperationGetBlock(operation) };
+
+        if block.ptr.is_null() {
+            return None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{Module, RegionLike, operation::OperationLike},
+        test::create_test_context,
+    };
+    use indoc::indoc;
+
+    const DIAMOND: &str = indoc!(
+        "
+        func.func @foo(%arg0: i1) {
+          cf.cond_br %arg0, ^bb1, ^bb2
+        ^bb1:
+          cf.br ^bb3
+        ^bb2:
+          cf.br ^bb3
+        ^bb3:
+          return
+        }
+        "
+    );
+
+    fn blocks<'c, 'a>(module: &'a Module<'c>) -> Vec<BlockRef<'c, 'a>> {
+        let region = module.body().first_operation().unwrap().region(0).unwrap();
+        let mut blocks = vec![];
+        let mut block = region.first_block();
+
+        while let Some(current) = block {
+            blocks.push(current);
+            block = current.next_in_region();
+        }
+
+        blocks
+    }
+
+    #[test]
+    fn dominates_block() {
+        let context = create_test_context();
+        let module = Module::parse(&context, DIAMOND).unwrap();
+        let info = DominanceInfo::new(module.as_operation());
+        let blocks = blocks(&module);
+
+        assert!(info.dominates_block(blocks[0], blocks[0]));
+        assert!(!info.properly_dominates_block(blocks[0], blocks[0]));
+        assert!(info.properly_dominates_block(blocks[0], blocks[3]));
+        assert!(!info.dominates_block(blocks[1], blocks[3]));
+        assert!(!info.dominates_block(blocks[3], blocks[0]));
+    }
+
+    #[test]
+    fn dominator_tree() {
+        let context = create_test_context();
+        let module = Module::parse(&context, DIAMOND).unwrap();
+        let info = DominanceInfo::new(module.as_operation());
+        let blocks = blocks(&module);
+
+        assert_eq!(info.immediate_dominator(blocks[0]), None);
+        assert_eq!(info.immediate_dominator(blocks[3]), Some(blocks[0]));
+        assert_eq!(
+            info.nearest_common_dominator(blocks[1], blocks[2]),
+            Some(blocks[0])
+        );
+        assert_eq!(info.children(blocks[0]).count(), 3);
+        assert_eq!(info.children(blocks[1]).count(), 0);
+        assert!(info.is_reachable_from_entry(blocks[3]));
+    }
+
+    #[test]
+    fn dominates_operation() {
+        let context = create_test_context();
+        let module = Module::parse(&context, DIAMOND).unwrap();
+        let info = DominanceInfo::new(module.as_operation());
+        let blocks = blocks(&module);
+        let branch = blocks[0].first_operation().unwrap();
+        let r#return = blocks[3].first_operation().unwrap();
+        let function = module.body().first_operation().unwrap();
+
+        assert!(info.dominates(branch, branch));
+        assert!(!info.properly_dominates(branch, branch));
+        assert!(info.properly_dominates(branch, r#return));
+        assert!(!info.dominates(r#return, branch));
+        assert!(info.properly_dominates(function, r#return));
+    }
+
+    #[test]
+    fn dominates_outside_operation() {
+        let context = create_test_context();
+        let module = Module::parse(&context, DIAMOND).unwrap();
+        let function = module.body().first_operation().unwrap();
+        let info = DominanceInfo::new(function);
+        let blocks = blocks(&module);
+        let body = module.body();
+
+        assert!(info.properly_dominates_block(blocks[0], blocks[3]));
+        assert!(!info.dominates_block(body, body));
+        assert!(!info.properly_dominates(function, blocks[3].first_operation().unwrap()));
+        assert!(!info.is_reachable_from_entry(body));
+    }
+
+    #[test]
+    fn dominates_value() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0: index) -> index {
+                  %0 = arith.addi %arg0, %arg0 : index
+                  %1 = arith.addi %0, %0 : index
+                  return %1 : index
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let info = DominanceInfo::new(module.as_operation());
+        let block = blocks(&module)[0];
+        let one = block.first_operation().unwrap();
+        let other = one.next_in_block().unwrap();
+
+        assert!(info.properly_dominates_value(block.argument(0).unwrap(), one));
+        assert!(info.properly_dominates_value(one.result(0).unwrap(), other));
+        assert!(!info.properly_dominates_value(one.result(0).unwrap(), one));
+        assert!(info.dominates_value(one.result(0).unwrap(), one));
+        assert!(!info.dominates_value(other.result(0).unwrap(), one));
+    }
+
+    #[test]
+    fn post_dominates_block() {
+        let context = create_test_context();
+        let module = Module::parse(&context, DIAMOND).unwrap();
+        let info = PostDominanceInfo::new(module.as_operation());
+        let blocks = blocks(&module);
+
+        assert!(info.post_dominates_block(blocks[3], blocks[0]));
+        assert!(info.properly_post_dominates_block(blocks[3], blocks[1]));
+        assert!(!info.post_dominates_block(blocks[0], blocks[1]));
+        assert!(!info.post_dominates_block(blocks[1], blocks[0]));
+    }
+
+    #[test]
+    fn post_dominator_tree() {
+        let context = create_test_context();
+        let module = Module::parse(&context, DIAMOND).unwrap();
+        let info = PostDominanceInfo::new(module.as_operation());
+        let blocks = blocks(&module);
+
+        assert_eq!(info.immediate_post_dominator(blocks[0]), Some(blocks[3]));
+        assert_eq!(info.immediate_post_dominator(blocks[3]), None);
+        assert_eq!(
+            info.nearest_common_post_dominator(blocks[1], blocks[2]),
+            Some(blocks[3])
+        );
+        assert_eq!(info.children(blocks[3]).count(), 3);
+    }
+
+    #[test]
+    fn post_dominates_operation() {
+        let context = create_test_context();
+        let module = Module::parse(&context, DIAMOND).unwrap();
+        let info = PostDominanceInfo::new(module.as_operation());
+        let blocks = blocks(&module);
+        let branch = blocks[0].first_operation().unwrap();
+        let r#return = blocks[3].first_operation().unwrap();
+
+        assert!(info.post_dominates(r#return, r#return));
+        assert!(info.properly_post_dominates(r#return, branch));
+        assert!(!info.post_dominates(branch, r#return));
+    }
+}
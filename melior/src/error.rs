@@ -1,3 +1,4 @@
+use crate::diagnostic::DetachedDiagnostic;
 use std::{
     convert::Infallible,
     error,
@@ -8,17 +9,26 @@ use std::{
 /// A Melior error.
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error {
+    AlignmentMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    ApplyConversion(Vec<DetachedDiagnostic>),
     ApplyPatterns,
     AttributeExpected(&'static str, String),
     AttributeNotFound(String),
     AttributeParse(String),
     BlockArgumentExpected(String),
+    DataSizeMismatch {
+        expected: usize,
+        actual: usize,
+    },
     ElementExpected {
         r#type: &'static str,
         value: String,
     },
     InvokeFunction,
-    OperationBuild,
+    OperationBuild(Vec<DetachedDiagnostic>),
     OperandNotFound(&'static str),
     OperationExpected {
         expected: &'static str,
@@ -31,10 +41,12 @@ pub enum Error {
         value: String,
         index: usize,
     },
+    ReplaceSymbolUses(String),
     ResultNotFound(&'static str),
-    RunPass,
+    RunPass(Vec<DetachedDiagnostic>),
     TypeExpected(&'static str, String),
     UnknownDiagnosticSeverity(u32),
+    UnknownSymbolVisibility(String),
     Utf8(Utf8Error),
     WriteBytecode,
 }
@@ -42,6 +54,16 @@ pub enum Error {
 impl Display for Error {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         match self {
+            Self::AlignmentMismatch { expected, actual } => {
+                write!(
+                    formatter,
+                    "alignment {actual} is not a power of two multiple of element alignment {expected}"
+                )
+            }
+            Self::ApplyConversion(diagnostics) => {
+                write!(formatter, "failed to apply conversion")?;
+                write_diagnostics(formatter, diagnostics)
+            }
             Self::ApplyPatterns => write!(formatter, "failed to apply patterns"),
             Self::AttributeExpected(r#type, attribute) => {
                 write!(formatter, "{type} attribute expected: {attribute}")
@@ -55,12 +77,19 @@ impl Display for Error {
             Self::BlockArgumentExpected(value) => {
                 write!(formatter, "block argument expected: {value}")
             }
+            Self::DataSizeMismatch { expected, actual } => {
+                write!(
+                    formatter,
+                    "data size {actual} does not match expected size {expected}"
+                )
+            }
             Self::ElementExpected { r#type, value } => {
                 write!(formatter, "element of {type} type expected: {value}")
             }
             Self::InvokeFunction => write!(formatter, "failed to invoke JIT-compiled function"),
-            Self::OperationBuild => {
-                write!(formatter, "operation build failed")
+            Self::OperationBuild(diagnostics) => {
+                write!(formatter, "operation build failed")?;
+                write_diagnostics(formatter, diagnostics)
             }
             Self::OperandNotFound(name) => {
                 write!(formatter, "operand {name} not found")
@@ -77,16 +106,25 @@ impl Display for Error {
             Self::PositionOutOfBounds { name, value, index } => {
                 write!(formatter, "{name} position {index} out of bounds: {value}")
             }
+            Self::ReplaceSymbolUses(symbol) => {
+                write!(formatter, "failed to replace uses of symbol {symbol}")
+            }
             Self::ResultNotFound(name) => {
                 write!(formatter, "result {name} not found")
             }
-            Self::RunPass => write!(formatter, "failed to run pass"),
+            Self::RunPass(diagnostics) => {
+                write!(formatter, "failed to run pass")?;
+                write_diagnostics(formatter, diagnostics)
+            }
             Self::TypeExpected(r#type, actual) => {
                 write!(formatter, "{type} type expected: {actual}")
             }
             Self::UnknownDiagnosticSeverity(severity) => {
                 write!(formatter, "unknown diagnostic severity: {severity}")
             }
+            Self::UnknownSymbolVisibility(visibility) => {
+                write!(formatter, "unknown symbol visibility: {visibility}")
+            }
             Self::Utf8(error) => {
                 write!(formatter, "{error}")
             }
@@ -95,6 +133,14 @@ impl Display for Error {
     }
 }
 
+fn write_diagnostics(formatter: &mut Formatter, diagnostics: &[DetachedDiagnostic]) -> fmt::Result {
+    for diagnostic in diagnostics {
+        write!(formatter, "\n{diagnostic}")?;
+    }
+
+    Ok(())
+}
+
 impl error::Error for Error {}
 
 impl From<Utf8Error> for Error {
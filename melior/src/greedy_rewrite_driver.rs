@@ -3,6 +3,7 @@ use crate::{
     ir::{Module, OperationRef},
     logical_result::LogicalResult,
     rewrite_pattern::FrozenRewritePatternSet,
+    rewriter_listener::{RewriterListener, with_active_listener},
 };
 use mlir_sys::{
     MlirGreedyRewriteDriverConfig, MlirGreedyRewriteStrictness,
@@ -12,11 +13,11 @@ use mlir_sys::{
     MlirGreedySimplifyRegionLevel,
     MlirGreedySimplifyRegionLevel_MLIR_GREEDY_SIMPLIFY_REGION_LEVEL_AGGRESSIVE,
     MlirGreedySimplifyRegionLevel_MLIR_GREEDY_SIMPLIFY_REGION_LEVEL_DISABLED,
-    MlirGreedySimplifyRegionLevel_MLIR_GREEDY_SIMPLIFY_REGION_LEVEL_NORMAL,
-    mlirApplyPatternsAndFoldGreedily, mlirGreedyRewriteDriverConfigCreate,
-    mlirGreedyRewriteDriverConfigDestroy, mlirGreedyRewriteDriverConfigEnableConstantCSE,
-    mlirGreedyRewriteDriverConfigEnableFolding, mlirGreedyRewriteDriverConfigGetMaxIterations,
-    mlirGreedyRewriteDriverConfigGetMaxNumRewrites,
+    MlirGreedySimplifyRegionLevel_MLIR_GREEDY_SIMPLIFY_REGION_LEVEL_NORMAL, MlirLogicalResult,
+    mlirApplyPatternsAndFoldGreedily, mlirApplyPatternsAndFoldGreedilyWithOp,
+    mlirGreedyRewriteDriverConfigCreate, mlirGreedyRewriteDriverConfigDestroy,
+    mlirGreedyRewriteDriverConfigEnableConstantCSE, mlirGreedyRewriteDriverConfigEnableFolding,
+    mlirGreedyRewriteDriverConfigGetMaxIterations, mlirGreedyRewriteDriverConfigGetMaxNumRewrites,
     mlirGreedyRewriteDriverConfigGetRegionSimplificationLevel,
     mlirGreedyRewriteDriverConfigGetStrictness,
     mlirGreedyRewriteDriverConfigGetUseTopDownTraversal,
@@ -27,6 +28,7 @@ use mlir_sys::{
     mlirGreedyRewriteDriverConfigSetStrictness,
     mlirGreedyRewriteDriverConfigSetUseTopDownTraversal, mlirWalkAndApplyPatterns,
 };
+use std::cell::RefCell;
 
 /// Strictness level for the greedy rewrite driver.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -88,6 +90,7 @@ impl GreedySimplifyRegionLevel {
 /// Configuration for the greedy rewrite driver.
 pub struct GreedyRewriteDriverConfig {
     raw: MlirGreedyRewriteDriverConfig,
+    listener: Option<Box<RefCell<dyn RewriterListener>>>,
 }
 
 impl GreedyRewriteDriverConfig {
@@ -95,6 +98,7 @@ impl GreedyRewriteDriverConfig {
     pub fn new() -> Self {
         Self {
             raw: unsafe { mlirGreedyRewriteDriverConfigCreate() },
+            listener: None,
         }
     }
 
@@ -182,6 +186,16 @@ impl GreedyRewriteDriverConfig {
         unsafe { mlirGreedyRewriteDriverConfigIsConstantCSEEnabled(self.raw) }
     }
 
+    /// Sets a listener notified of changes made by patterns.
+    ///
+    /// Only changes made through [`PatternRewriter`](crate::PatternRewriter)s
+    /// of patterns defined in Rust are notified. Operations folded or erased as
+    /// dead code by the driver itself are not as the C API cannot attach
+    /// listeners to the driver.
+    pub fn set_listener(&mut self, listener: impl RewriterListener + 'static) {
+        self.listener = Some(Box::new(RefCell::new(listener)));
+    }
+
     /// Converts the config into a raw object.
     pub const fn to_raw(&self) -> MlirGreedyRewriteDriverConfig {
         self.raw
@@ -204,13 +218,44 @@ impl Drop for GreedyRewriteDriverConfig {
 ///
 /// The `patterns` argument is consumed (its ownership is transferred to the C
 /// layer).
+///
+/// It returns [`Error::ApplyPatterns`] if the driver does not converge within
+/// the maximum number of iterations and rewrites. Whether IR is changed and
+/// the number of iterations are not reported as the C API does not expose
+/// them.
 pub fn apply_patterns_and_fold_greedily(
     module: &Module,
     patterns: FrozenRewritePatternSet,
     config: &GreedyRewriteDriverConfig,
 ) -> Result<(), Error> {
-    let result = LogicalResult::from_raw(unsafe {
+    apply(config, || unsafe {
         mlirApplyPatternsAndFoldGreedily(module.to_raw(), patterns.into_raw(), config.to_raw())
+    })
+}
+
+/// Applies patterns and folds greedily to operations nested in the regions of
+/// the given operation.
+///
+/// The `patterns` argument is consumed. The operation itself is not rewritten.
+///
+/// It returns [`Error::ApplyPatterns`] if the driver does not converge within
+/// the maximum number of iterations and rewrites.
+pub fn apply_op_patterns_greedily(
+    op: OperationRef,
+    patterns: FrozenRewritePatternSet,
+    config: &GreedyRewriteDriverConfig,
+) -> Result<(), Error> {
+    apply(config, || unsafe {
+        mlirApplyPatternsAndFoldGreedilyWithOp(op.to_raw(), patterns.into_raw(), config.to_raw())
+    })
+}
+
+fn apply(
+    config: &GreedyRewriteDriverConfig,
+    function: impl FnOnce() -> MlirLogicalResult,
+) -> Result<(), Error> {
+    let result = with_active_listener(config.listener.as_deref(), || {
+        LogicalResult::from_raw(function())
     });
 
     if result.is_success() {
@@ -231,8 +276,8 @@ pub fn walk_and_apply_patterns(op: OperationRef, patterns: FrozenRewritePatternS
 mod tests {
     use super::*;
     use crate::{
-        ir::{Location, Module},
-        rewrite_pattern::RewritePatternSet,
+        ir::{BlockLike, Location, Module, RegionLike, operation::OperationLike},
+        rewrite_pattern::{PatternRewriter, RewritePatternSet},
         test::create_test_context,
     };
 
@@ -334,4 +379,74 @@ mod tests {
 
         assert!(apply_patterns_and_fold_greedily(&module, frozen, &config).is_ok());
     }
+
+    #[test]
+    fn apply_op_patterns() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let module = Module::parse(
+            &context,
+            r#"
+            "foo.baz"() ({
+              "foo.bar"() : () -> ()
+            }) : () -> ()
+            "foo.bar"() : () -> ()
+            "#,
+        )
+        .unwrap();
+        let patterns = RewritePatternSet::new(&context);
+        patterns.add_pattern(
+            "foo.bar",
+            1,
+            |operation: OperationRef, rewriter: &PatternRewriter| {
+                rewriter.as_rewriter_base().erase_op(operation);
+                true
+            },
+        );
+
+        let operation = module.body().first_operation().unwrap();
+
+        apply_op_patterns_greedily(operation, patterns.freeze(), &Default::default()).unwrap();
+
+        assert!(
+            operation
+                .region(0)
+                .unwrap()
+                .first_block()
+                .unwrap()
+                .first_operation()
+                .is_none()
+        );
+        assert_eq!(module.body().operations().count(), 2);
+    }
+
+    #[test]
+    fn fail_without_convergence() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let module = Module::parse(&context, r#""foo.bar"() : () -> ()"#).unwrap();
+        let patterns = RewritePatternSet::new(&context);
+        patterns.add_pattern(
+            "foo.bar",
+            1,
+            |operation: OperationRef, rewriter: &PatternRewriter| {
+                let rewriter = rewriter.as_rewriter_base();
+
+                rewriter.start_op_modification(operation);
+                rewriter.finalize_op_modification(operation);
+                true
+            },
+        );
+
+        let config = GreedyRewriteDriverConfig::new();
+        config.set_max_iterations(2);
+        config.set_max_num_rewrites(8);
+
+        assert_eq!(
+            apply_patterns_and_fold_greedily(&module, patterns.freeze(), &config),
+            Err(Error::ApplyPatterns)
+        );
+    }
 }
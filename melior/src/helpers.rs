@@ -1,10 +1,48 @@
 //! Traits that extends the [`Block`](crate::ir::Block) type to aid in code
 //! generation and consistency.
 
+macro_rules! binary_operation_declaration {
+    ($name:ident, $documentation:literal) => {
+        #[doc=$documentation]
+        fn $name(
+            &self,
+            lhs: Value<'c, '_>,
+            rhs: Value<'c, '_>,
+            location: Location<'c>,
+        ) -> Result<Value<'c, '_>, Error>;
+    };
+}
+
+macro_rules! binary_operation {
+    ($name:ident) => {
+        #[inline]
+        fn $name(
+            &self,
+            lhs: Value<'c, '_>,
+            rhs: Value<'c, '_>,
+            location: Location<'c>,
+        ) -> Result<Value<'c, '_>, Error> {
+            self.append_op_result($name(lhs, rhs, location))
+        }
+    };
+}
+
 mod arith;
 mod builtin;
+mod cf;
+mod func;
+mod index;
 mod llvm;
+mod math;
+mod memref;
+mod scf;
 
 pub use arith::ArithBlockExt;
 pub use builtin::BuiltinBlockExt;
+pub use cf::CfBlockExt;
+pub use func::FuncBlockExt;
+pub use index::IndexBlockExt;
 pub use llvm::{GepIndex, LlvmBlockExt};
+pub use math::MathBlockExt;
+pub use memref::MemrefBlockExt;
+pub use scf::ScfBlockExt;
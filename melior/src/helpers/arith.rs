@@ -12,32 +12,6 @@ use crate::{
 };
 use core::fmt::Display;
 
-macro_rules! binary_operation_declaration {
-    ($name:ident, $documentation:literal) => {
-        #[doc=$documentation]
-        fn $name(
-            &self,
-            lhs: Value<'c, '_>,
-            rhs: Value<'c, '_>,
-            location: Location<'c>,
-        ) -> Result<Value<'c, '_>, Error>;
-    };
-}
-
-macro_rules! binary_operation {
-    ($name:ident) => {
-        #[inline]
-        fn $name(
-            &self,
-            lhs: Value<'c, '_>,
-            rhs: Value<'c, '_>,
-            location: Location<'c>,
-        ) -> Result<Value<'c, '_>, Error> {
-            self.append_op_result($name(lhs, rhs, location))
-        }
-    };
-}
-
 /// A block extension for an `arith` dialect.
 pub trait ArithBlockExt<'c>: BuiltinBlockExt<'c> {
     binary_operation_declaration!(addi, "Creates an `arith.addi` operation.");
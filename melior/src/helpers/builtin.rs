@@ -1,6 +1,6 @@
 use crate::{
     Error,
-    ir::{Block, Operation, Value, block::BlockLike},
+    ir::{Block, Operation, Value, block::BlockLike, operation::OperationLike},
 };
 
 /// A block extension for a `builtin` dialect and constructs.
@@ -10,6 +10,17 @@ pub trait BuiltinBlockExt<'c> {
 
     /// Appends an operation and returns its first value.
     fn append_op_result(&self, operation: Operation<'c>) -> Result<Value<'c, '_>, Error>;
+
+    /// Appends an operation and returns its values.
+    fn append_op_results<'a>(&'a self, operation: Operation<'c>) -> Vec<Value<'c, 'a>>
+    where
+        Self: BlockLike<'c, 'a>,
+    {
+        self.append_operation(operation)
+            .results()
+            .map(Into::into)
+            .collect()
+    }
 }
 
 impl<'c> BuiltinBlockExt<'c> for Block<'c> {
@@ -0,0 +1,121 @@
+use super::builtin::BuiltinBlockExt;
+use crate::{
+    Context, Error,
+    dialect::cf,
+    ir::{Block, Location, Type, Value, block::BlockLike},
+};
+
+/// A block extension for a `cf` dialect.
+pub trait CfBlockExt<'c>: BuiltinBlockExt<'c> {
+    /// Creates a `cf.assert` operation.
+    fn assert(
+        &self,
+        context: &'c Context,
+        argument: Value<'c, '_>,
+        message: &str,
+        location: Location<'c>,
+    );
+
+    /// Creates a `cf.br` operation.
+    fn br(
+        &self,
+        successor: &Block<'c>,
+        destination_operands: &[Value<'c, '_>],
+        location: Location<'c>,
+    );
+
+    /// Creates a `cf.cond_br` operation.
+    #[allow(clippy::too_many_arguments)]
+    fn cond_br(
+        &self,
+        context: &'c Context,
+        condition: Value<'c, '_>,
+        true_successor: &Block<'c>,
+        false_successor: &Block<'c>,
+        true_successor_operands: &[Value<'c, '_>],
+        false_successor_operands: &[Value<'c, '_>],
+        location: Location<'c>,
+    );
+
+    /// Creates a `cf.switch` operation.
+    #[allow(clippy::too_many_arguments)]
+    fn switch(
+        &self,
+        context: &'c Context,
+        case_values: &[i64],
+        flag: Value<'c, '_>,
+        flag_type: Type<'c>,
+        default_destination: (&Block<'c>, &[Value<'c, '_>]),
+        case_destinations: &[(&Block<'c>, &[Value<'c, '_>])],
+        location: Location<'c>,
+    ) -> Result<(), Error>;
+}
+
+impl<'c> CfBlockExt<'c> for Block<'c> {
+    #[inline]
+    fn assert(
+        &self,
+        context: &'c Context,
+        argument: Value<'c, '_>,
+        message: &str,
+        location: Location<'c>,
+    ) {
+        self.append_operation(cf::assert(context, argument, message, location));
+    }
+
+    #[inline]
+    fn br(
+        &self,
+        successor: &Block<'c>,
+        destination_operands: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) {
+        self.append_operation(cf::br(successor, destination_operands, location));
+    }
+
+    #[inline]
+    fn cond_br(
+        &self,
+        context: &'c Context,
+        condition: Value<'c, '_>,
+        true_successor: &Block<'c>,
+        false_successor: &Block<'c>,
+        true_successor_operands: &[Value<'c, '_>],
+        false_successor_operands: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) {
+        self.append_operation(cf::cond_br(
+            context,
+            condition,
+            true_successor,
+            false_successor,
+            true_successor_operands,
+            false_successor_operands,
+            location,
+        ));
+    }
+
+    #[inline]
+    fn switch(
+        &self,
+        context: &'c Context,
+        case_values: &[i64],
+        flag: Value<'c, '_>,
+        flag_type: Type<'c>,
+        default_destination: (&Block<'c>, &[Value<'c, '_>]),
+        case_destinations: &[(&Block<'c>, &[Value<'c, '_>])],
+        location: Location<'c>,
+    ) -> Result<(), Error> {
+        self.append_operation(cf::switch(
+            context,
+            case_values,
+            flag,
+            flag_type,
+            default_destination,
+            case_destinations,
+            location,
+        )?);
+
+        Ok(())
+    }
+}
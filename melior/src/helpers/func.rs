@@ -0,0 +1,100 @@
+use super::builtin::BuiltinBlockExt;
+use crate::{
+    Context, Error,
+    dialect::func,
+    ir::{
+        Block, Location, Type, Value, attribute::FlatSymbolRefAttribute, block::BlockLike,
+        r#type::FunctionType,
+    },
+};
+
+/// A block extension for a `func` dialect.
+pub trait FuncBlockExt<'c>: BuiltinBlockExt<'c> {
+    /// Creates a `func.call` operation.
+    fn call(
+        &self,
+        context: &'c Context,
+        function: &str,
+        arguments: &[Value<'c, '_>],
+        result_types: &[Type<'c>],
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>;
+
+    /// Creates a `func.call_indirect` operation.
+    fn call_indirect(
+        &self,
+        function: Value<'c, '_>,
+        arguments: &[Value<'c, '_>],
+        result_types: &[Type<'c>],
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>;
+
+    /// Creates a `func.constant` operation.
+    fn func_constant(
+        &self,
+        context: &'c Context,
+        function: &str,
+        r#type: FunctionType<'c>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+
+    /// Creates a `func.return` operation.
+    fn r#return(&self, values: &[Value<'c, '_>], location: Location<'c>);
+}
+
+impl<'c> FuncBlockExt<'c> for Block<'c> {
+    #[inline]
+    fn call(
+        &self,
+        context: &'c Context,
+        function: &str,
+        arguments: &[Value<'c, '_>],
+        result_types: &[Type<'c>],
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>> {
+        self.append_op_results(func::call(
+            context,
+            FlatSymbolRefAttribute::new(context, function),
+            arguments,
+            result_types,
+            location,
+        ))
+    }
+
+    #[inline]
+    fn call_indirect(
+        &self,
+        function: Value<'c, '_>,
+        arguments: &[Value<'c, '_>],
+        result_types: &[Type<'c>],
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>> {
+        self.append_op_results(func::call_indirect(
+            function,
+            arguments,
+            result_types,
+            location,
+        ))
+    }
+
+    #[inline]
+    fn func_constant(
+        &self,
+        context: &'c Context,
+        function: &str,
+        r#type: FunctionType<'c>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(func::constant(
+            context,
+            FlatSymbolRefAttribute::new(context, function),
+            r#type,
+            location,
+        ))
+    }
+
+    #[inline]
+    fn r#return(&self, values: &[Value<'c, '_>], location: Location<'c>) {
+        self.append_operation(func::r#return(values, location));
+    }
+}
@@ -0,0 +1,139 @@
+use super::builtin::BuiltinBlockExt;
+use crate::{
+    Context, Error,
+    dialect::{
+        arith::CmpiPredicate,
+        index::{
+            add, and, casts, castu, ceildivs, ceildivu, cmp, constant, divs, divu, floordivs, maxs,
+            maxu, mins, minu, mul, or, rems, remu, shl, shrs, shru, sub, xor,
+        },
+    },
+    ir::{Block, Location, Type, Value, attribute::IntegerAttribute},
+};
+
+/// A block extension for an `index` dialect.
+pub trait IndexBlockExt<'c>: BuiltinBlockExt<'c> {
+    binary_operation_declaration!(add, "Creates an `index.add` operation.");
+    binary_operation_declaration!(and, "Creates an `index.and` operation.");
+    binary_operation_declaration!(ceildivs, "Creates an `index.ceildivs` operation.");
+    binary_operation_declaration!(ceildivu, "Creates an `index.ceildivu` operation.");
+    binary_operation_declaration!(divs, "Creates an `index.divs` operation.");
+    binary_operation_declaration!(divu, "Creates an `index.divu` operation.");
+    binary_operation_declaration!(floordivs, "Creates an `index.floordivs` operation.");
+    binary_operation_declaration!(maxs, "Creates an `index.maxs` operation.");
+    binary_operation_declaration!(maxu, "Creates an `index.maxu` operation.");
+    binary_operation_declaration!(mins, "Creates an `index.mins` operation.");
+    binary_operation_declaration!(minu, "Creates an `index.minu` operation.");
+    binary_operation_declaration!(mul, "Creates an `index.mul` operation.");
+    binary_operation_declaration!(or, "Creates an `index.or` operation.");
+    binary_operation_declaration!(rems, "Creates an `index.rems` operation.");
+    binary_operation_declaration!(remu, "Creates an `index.remu` operation.");
+    binary_operation_declaration!(shl, "Creates an `index.shl` operation.");
+    binary_operation_declaration!(shrs, "Creates an `index.shrs` operation.");
+    binary_operation_declaration!(shru, "Creates an `index.shru` operation.");
+    binary_operation_declaration!(sub, "Creates an `index.sub` operation.");
+    binary_operation_declaration!(xor, "Creates an `index.xor` operation.");
+
+    /// Creates an `index.casts` operation.
+    fn casts(
+        &self,
+        value: Value<'c, '_>,
+        target_type: Type<'c>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+
+    /// Creates an `index.castu` operation.
+    fn castu(
+        &self,
+        value: Value<'c, '_>,
+        target_type: Type<'c>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+
+    /// Creates an `index.cmp` operation.
+    fn cmp(
+        &self,
+        context: &'c Context,
+        predicate: CmpiPredicate,
+        lhs: Value<'c, '_>,
+        rhs: Value<'c, '_>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+
+    /// Creates an `index.constant` operation.
+    fn const_index(
+        &self,
+        context: &'c Context,
+        location: Location<'c>,
+        value: i64,
+    ) -> Result<Value<'c, '_>, Error>;
+}
+
+impl<'c> IndexBlockExt<'c> for Block<'c> {
+    binary_operation!(add);
+    binary_operation!(and);
+    binary_operation!(ceildivs);
+    binary_operation!(ceildivu);
+    binary_operation!(divs);
+    binary_operation!(divu);
+    binary_operation!(floordivs);
+    binary_operation!(maxs);
+    binary_operation!(maxu);
+    binary_operation!(mins);
+    binary_operation!(minu);
+    binary_operation!(mul);
+    binary_operation!(or);
+    binary_operation!(rems);
+    binary_operation!(remu);
+    binary_operation!(shl);
+    binary_operation!(shrs);
+    binary_operation!(shru);
+    binary_operation!(sub);
+    binary_operation!(xor);
+
+    #[inline]
+    fn casts(
+        &self,
+        value: Value<'c, '_>,
+        target_type: Type<'c>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(casts(value, target_type, location))
+    }
+
+    #[inline]
+    fn castu(
+        &self,
+        value: Value<'c, '_>,
+        target_type: Type<'c>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(castu(value, target_type, location))
+    }
+
+    #[inline]
+    fn cmp(
+        &self,
+        context: &'c Context,
+        predicate: CmpiPredicate,
+        lhs: Value<'c, '_>,
+        rhs: Value<'c, '_>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(cmp(context, predicate, lhs, rhs, location))
+    }
+
+    #[inline]
+    fn const_index(
+        &self,
+        context: &'c Context,
+        location: Location<'c>,
+        value: i64,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(constant(
+            context,
+            IntegerAttribute::new(Type::index(context), value),
+            location,
+        ))
+    }
+}
@@ -0,0 +1,131 @@
+use super::builtin::BuiltinBlockExt;
+use crate::{
+    Context, Error,
+    dialect::ods,
+    ir::{Block, Location, Value},
+};
+
+macro_rules! unary_operation_declaration {
+    ($name:ident, $documentation:literal) => {
+        #[doc=$documentation]
+        fn $name(
+            &self,
+            context: &'c Context,
+            value: Value<'c, '_>,
+            location: Location<'c>,
+        ) -> Result<Value<'c, '_>, Error>;
+    };
+}
+
+macro_rules! unary_operation {
+    ($name:ident) => {
+        #[inline]
+        fn $name(
+            &self,
+            context: &'c Context,
+            value: Value<'c, '_>,
+            location: Location<'c>,
+        ) -> Result<Value<'c, '_>, Error> {
+            self.append_op_result(ods::math::$name(context, value, location).into())
+        }
+    };
+}
+
+macro_rules! math_binary_operation_declaration {
+    ($name:ident, $documentation:literal) => {
+        #[doc=$documentation]
+        fn $name(
+            &self,
+            context: &'c Context,
+            lhs: Value<'c, '_>,
+            rhs: Value<'c, '_>,
+            location: Location<'c>,
+        ) -> Result<Value<'c, '_>, Error>;
+    };
+}
+
+macro_rules! math_binary_operation {
+    ($name:ident) => {
+        #[inline]
+        fn $name(
+            &self,
+            context: &'c Context,
+            lhs: Value<'c, '_>,
+            rhs: Value<'c, '_>,
+            location: Location<'c>,
+        ) -> Result<Value<'c, '_>, Error> {
+            self.append_op_result(ods::math::$name(context, lhs, rhs, location).into())
+        }
+    };
+}
+
+/// A block extension for a `math` dialect.
+pub trait MathBlockExt<'c>: BuiltinBlockExt<'c> {
+    unary_operation_declaration!(absf, "Creates a `math.absf` operation.");
+    unary_operation_declaration!(absi, "Creates a `math.absi` operation.");
+    unary_operation_declaration!(ceil, "Creates a `math.ceil` operation.");
+    unary_operation_declaration!(cos, "Creates a `math.cos` operation.");
+    unary_operation_declaration!(ctlz, "Creates a `math.ctlz` operation.");
+    unary_operation_declaration!(cttz, "Creates a `math.cttz` operation.");
+    unary_operation_declaration!(ctpop, "Creates a `math.ctpop` operation.");
+    unary_operation_declaration!(exp, "Creates a `math.exp` operation.");
+    unary_operation_declaration!(exp2, "Creates a `math.exp2` operation.");
+    unary_operation_declaration!(floor, "Creates a `math.floor` operation.");
+    unary_operation_declaration!(log, "Creates a `math.log` operation.");
+    unary_operation_declaration!(log2, "Creates a `math.log2` operation.");
+    unary_operation_declaration!(round, "Creates a `math.round` operation.");
+    unary_operation_declaration!(rsqrt, "Creates a `math.rsqrt` operation.");
+    unary_operation_declaration!(sin, "Creates a `math.sin` operation.");
+    unary_operation_declaration!(sqrt, "Creates a `math.sqrt` operation.");
+    unary_operation_declaration!(tanh, "Creates a `math.tanh` operation.");
+    unary_operation_declaration!(trunc, "Creates a `math.trunc` operation.");
+    math_binary_operation_declaration!(atan2, "Creates a `math.atan2` operation.");
+    math_binary_operation_declaration!(copysign, "Creates a `math.copysign` operation.");
+    math_binary_operation_declaration!(powf, "Creates a `math.powf` operation.");
+
+    /// Creates a `math.fma` operation.
+    fn fma(
+        &self,
+        context: &'c Context,
+        a: Value<'c, '_>,
+        b: Value<'c, '_>,
+        c: Value<'c, '_>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+}
+
+impl<'c> MathBlockExt<'c> for Block<'c> {
+    unary_operation!(absf);
+    unary_operation!(absi);
+    unary_operation!(ceil);
+    unary_operation!(cos);
+    unary_operation!(ctlz);
+    unary_operation!(cttz);
+    unary_operation!(ctpop);
+    unary_operation!(exp);
+    unary_operation!(exp2);
+    unary_operation!(floor);
+    unary_operation!(log);
+    unary_operation!(log2);
+    unary_operation!(round);
+    unary_operation!(rsqrt);
+    unary_operation!(sin);
+    unary_operation!(sqrt);
+    unary_operation!(tanh);
+    unary_operation!(trunc);
+    math_binary_operation!(atan2);
+    math_binary_operation!(copysign);
+    math_binary_operation!(powf);
+
+    #[inline]
+    fn fma(
+        &self,
+        context: &'c Context,
+        a: Value<'c, '_>,
+        b: Value<'c, '_>,
+        c: Value<'c, '_>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(ods::math::fma(context, a, b, c, location).into())
+    }
+}
@@ -0,0 +1,183 @@
+use super::builtin::BuiltinBlockExt;
+use crate::{
+    Context, Error,
+    dialect::memref,
+    ir::{Block, Location, Value, block::BlockLike, r#type::MemRefType},
+};
+
+/// A block extension for a `memref` dialect.
+pub trait MemrefBlockExt<'c>: BuiltinBlockExt<'c> {
+    /// Creates a `memref.alloc` operation.
+    fn memref_alloc(
+        &self,
+        context: &'c Context,
+        r#type: MemRefType<'c>,
+        dynamic_sizes: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+
+    /// Creates a `memref.alloca` operation.
+    fn memref_alloca(
+        &self,
+        context: &'c Context,
+        r#type: MemRefType<'c>,
+        dynamic_sizes: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+
+    /// Creates a `memref.cast` operation.
+    fn memref_cast(
+        &self,
+        value: Value<'c, '_>,
+        r#type: MemRefType<'c>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+
+    /// Creates a `memref.dealloc` operation.
+    fn memref_dealloc(&self, value: Value<'c, '_>, location: Location<'c>);
+
+    /// Creates a `memref.dim` operation.
+    fn memref_dim(
+        &self,
+        value: Value<'c, '_>,
+        index: Value<'c, '_>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+
+    /// Creates a `memref.get_global` operation.
+    fn memref_get_global(
+        &self,
+        context: &'c Context,
+        name: &str,
+        r#type: MemRefType<'c>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+
+    /// Creates a `memref.load` operation.
+    fn memref_load(
+        &self,
+        memref: Value<'c, '_>,
+        indices: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+
+    /// Creates a `memref.rank` operation.
+    fn memref_rank(
+        &self,
+        value: Value<'c, '_>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error>;
+
+    /// Creates a `memref.store` operation.
+    fn memref_store(
+        &self,
+        value: Value<'c, '_>,
+        memref: Value<'c, '_>,
+        indices: &[Value<'c, '_>],
+        location: Location<'c>,
+    );
+}
+
+impl<'c> MemrefBlockExt<'c> for Block<'c> {
+    #[inline]
+    fn memref_alloc(
+        &self,
+        context: &'c Context,
+        r#type: MemRefType<'c>,
+        dynamic_sizes: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(memref::alloc(
+            context,
+            r#type,
+            dynamic_sizes,
+            &[],
+            None,
+            location,
+        ))
+    }
+
+    #[inline]
+    fn memref_alloca(
+        &self,
+        context: &'c Context,
+        r#type: MemRefType<'c>,
+        dynamic_sizes: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(memref::alloca(
+            context,
+            r#type,
+            dynamic_sizes,
+            &[],
+            None,
+            location,
+        ))
+    }
+
+    #[inline]
+    fn memref_cast(
+        &self,
+        value: Value<'c, '_>,
+        r#type: MemRefType<'c>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(memref::cast(value, r#type, location))
+    }
+
+    #[inline]
+    fn memref_dealloc(&self, value: Value<'c, '_>, location: Location<'c>) {
+        self.append_operation(memref::dealloc(value, location));
+    }
+
+    #[inline]
+    fn memref_dim(
+        &self,
+        value: Value<'c, '_>,
+        index: Value<'c, '_>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(memref::dim(value, index, location))
+    }
+
+    #[inline]
+    fn memref_get_global(
+        &self,
+        context: &'c Context,
+        name: &str,
+        r#type: MemRefType<'c>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(memref::get_global(context, name, r#type, location))
+    }
+
+    #[inline]
+    fn memref_load(
+        &self,
+        memref: Value<'c, '_>,
+        indices: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(memref::load(memref, indices, location))
+    }
+
+    #[inline]
+    fn memref_rank(
+        &self,
+        value: Value<'c, '_>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, '_>, Error> {
+        self.append_op_result(memref::rank(value, location))
+    }
+
+    #[inline]
+    fn memref_store(
+        &self,
+        value: Value<'c, '_>,
+        memref: Value<'c, '_>,
+        indices: &[Value<'c, '_>],
+        location: Location<'c>,
+    ) {
+        self.append_operation(memref::store(value, memref, indices, location));
+    }
+}
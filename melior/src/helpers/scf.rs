@@ -0,0 +1,193 @@
+use super::builtin::BuiltinBlockExt;
+use crate::{
+    Context,
+    dialect::scf,
+    ir::{Block, Location, Type, Value, block::BlockLike},
+};
+
+/// A block extension for an `scf` dialect.
+///
+/// Bodies of operations are built by closures as in the `build_*` functions of
+/// [`scf`](crate::dialect::scf), and results of the operations are returned.
+pub trait ScfBlockExt<'c>: BuiltinBlockExt<'c> {
+    /// Creates a `scf.execute_region` operation.
+    fn execute_region<'a>(
+        &self,
+        result_types: &[Type<'c>],
+        body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>
+    where
+        'c: 'a;
+
+    /// Creates a `scf.for` operation.
+    fn r#for<'a>(
+        &self,
+        start: Value<'c, '_>,
+        end: Value<'c, '_>,
+        step: Value<'c, '_>,
+        initial_values: &[Value<'c, '_>],
+        body: impl for<'b> FnOnce(&'b Block<'a>, Value<'a, 'b>, &[Value<'a, 'b>]) -> Vec<Value<'a, 'b>>,
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>
+    where
+        'c: 'a;
+
+    /// Creates a `scf.if` operation.
+    fn r#if<'a>(
+        &self,
+        condition: Value<'c, '_>,
+        result_types: &[Type<'c>],
+        then_body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+        else_body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>
+    where
+        'c: 'a;
+
+    /// Creates a `scf.index_switch` operation.
+    #[allow(clippy::too_many_arguments)]
+    fn index_switch<'a>(
+        &self,
+        context: &'c Context,
+        condition: Value<'c, '_>,
+        result_types: &[Type<'c>],
+        cases: &[i64],
+        default_body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+        case_body: impl for<'b> FnMut(&'b Block<'a>, i64) -> Vec<Value<'a, 'b>>,
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>
+    where
+        'c: 'a;
+
+    /// Creates a `scf.while` operation.
+    fn r#while<'a>(
+        &self,
+        initial_values: &[Value<'c, '_>],
+        result_types: &[Type<'c>],
+        before_body: impl for<'b> FnOnce(
+            &'b Block<'a>,
+            &[Value<'a, 'b>],
+        ) -> (Value<'a, 'b>, Vec<Value<'a, 'b>>),
+        after_body: impl for<'b> FnOnce(&'b Block<'a>, &[Value<'a, 'b>]) -> Vec<Value<'a, 'b>>,
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>
+    where
+        'c: 'a;
+
+    /// Creates a `scf.yield` operation.
+    fn r#yield(&self, values: &[Value<'c, '_>], location: Location<'c>);
+}
+
+impl<'c> ScfBlockExt<'c> for Block<'c> {
+    #[inline]
+    fn execute_region<'a>(
+        &self,
+        result_types: &[Type<'c>],
+        body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>
+    where
+        'c: 'a,
+    {
+        self.append_op_results(scf::build_execute_region(result_types, body, location))
+    }
+
+    #[inline]
+    fn r#for<'a>(
+        &self,
+        start: Value<'c, '_>,
+        end: Value<'c, '_>,
+        step: Value<'c, '_>,
+        initial_values: &[Value<'c, '_>],
+        body: impl for<'b> FnOnce(&'b Block<'a>, Value<'a, 'b>, &[Value<'a, 'b>]) -> Vec<Value<'a, 'b>>,
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>
+    where
+        'c: 'a,
+    {
+        self.append_op_results(scf::build_for(
+            start,
+            end,
+            step,
+            initial_values,
+            body,
+            location,
+        ))
+    }
+
+    #[inline]
+    fn r#if<'a>(
+        &self,
+        condition: Value<'c, '_>,
+        result_types: &[Type<'c>],
+        then_body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+        else_body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>
+    where
+        'c: 'a,
+    {
+        self.append_op_results(scf::build_if(
+            condition,
+            result_types,
+            then_body,
+            else_body,
+            location,
+        ))
+    }
+
+    #[inline]
+    fn index_switch<'a>(
+        &self,
+        context: &'c Context,
+        condition: Value<'c, '_>,
+        result_types: &[Type<'c>],
+        cases: &[i64],
+        default_body: impl for<'b> FnOnce(&'b Block<'a>) -> Vec<Value<'a, 'b>>,
+        case_body: impl for<'b> FnMut(&'b Block<'a>, i64) -> Vec<Value<'a, 'b>>,
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>
+    where
+        'c: 'a,
+    {
+        self.append_op_results(scf::build_index_switch(
+            context,
+            condition,
+            result_types,
+            cases,
+            default_body,
+            case_body,
+            location,
+        ))
+    }
+
+    #[inline]
+    fn r#while<'a>(
+        &self,
+        initial_values: &[Value<'c, '_>],
+        result_types: &[Type<'c>],
+        before_body: impl for<'b> FnOnce(
+            &'b Block<'a>,
+            &[Value<'a, 'b>],
+        ) -> (Value<'a, 'b>, Vec<Value<'a, 'b>>),
+        after_body: impl for<'b> FnOnce(&'b Block<'a>, &[Value<'a, 'b>]) -> Vec<Value<'a, 'b>>,
+        location: Location<'c>,
+    ) -> Vec<Value<'c, '_>>
+    where
+        'c: 'a,
+    {
+        self.append_op_results(scf::build_while(
+            initial_values,
+            result_types,
+            before_body,
+            after_body,
+            location,
+        ))
+    }
+
+    #[inline]
+    fn r#yield(&self, values: &[Value<'c, '_>], location: Location<'c>) {
+        self.append_operation(scf::r#yield(values, location));
+    }
+}
@@ -5,11 +5,17 @@ mod affine_map;
 pub mod attribute;
 pub mod block;
 pub mod bytecode_writer_config;
+mod downcast;
 mod identifier;
-mod location;
+mod integer_set;
+mod list_iterator;
+pub mod location;
+mod mapping;
 mod module;
+mod op_builder;
 pub mod operation;
 mod region;
+pub mod symbol_table;
 pub mod r#type;
 mod value;
 
@@ -19,11 +25,16 @@ pub use self::{
     attribute::{Attribute, AttributeLike},
     block::{Block, BlockLike, BlockRef},
     bytecode_writer_config::BytecodeWriterConfig,
+    downcast::Downcast,
     identifier::Identifier,
-    location::Location,
+    integer_set::IntegerSet,
+    location::{Location, LocationKind},
+    mapping::IrMapping,
     module::Module,
+    op_builder::{ImplicitLocOpBuilder, InsertionGuard, InsertionPoint, LocationGuard, OpBuilder},
     operation::{Operation, OperationRef},
     region::{Region, RegionLike, RegionRef},
+    symbol_table::SymbolTable,
     r#type::{ShapedTypeLike, Type, TypeLike},
     value::{Value, ValueLike},
 };
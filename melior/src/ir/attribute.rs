@@ -2,29 +2,47 @@
 
 #[macro_use]
 mod r#macro;
+mod affine_map;
 mod array;
 mod attribute_like;
 mod bool;
 mod dense_elements;
 mod dense_i32_array;
 mod dense_i64_array;
+mod dense_resource_elements;
 mod dictionary;
 mod distinct;
 mod flat_symbol_ref;
 mod float;
 mod integer;
+mod integer_set;
+mod location;
+mod opaque;
+mod sparse_elements;
 mod strided_layout;
 mod string;
+mod symbol_ref;
 mod r#type;
+mod unit;
 
 pub use self::{
-    array::ArrayAttribute, attribute_like::AttributeLike, bool::BoolAttribute,
-    dense_elements::DenseElementsAttribute, dense_i32_array::DenseI32ArrayAttribute,
-    dense_i64_array::DenseI64ArrayAttribute, dictionary::DictionaryAttribute,
+    affine_map::AffineMapAttribute, array::ArrayAttribute, attribute_like::AttributeLike,
+    bool::BoolAttribute, dense_elements::DenseElementsAttribute,
+    dense_i32_array::DenseI32ArrayAttribute, dense_i64_array::DenseI64ArrayAttribute,
+    dense_resource_elements::DenseResourceElementsAttribute, dictionary::DictionaryAttribute,
     flat_symbol_ref::FlatSymbolRefAttribute, float::FloatAttribute, integer::IntegerAttribute,
-    strided_layout::StridedLayoutAttribute, string::StringAttribute, r#type::TypeAttribute,
+    integer_set::IntegerSetAttribute, location::LocationAttribute, opaque::OpaqueAttribute,
+    sparse_elements::SparseElementsAttribute, strided_layout::StridedLayoutAttribute,
+    string::StringAttribute, symbol_ref::SymbolRefAttribute, r#type::TypeAttribute,
+    unit::UnitAttribute,
+};
+use crate::{
+    context::Context,
+    diagnostic::{OwnedDiagnostic, collect_diagnostics},
+    ir::Downcast,
+    string_ref::StringRef,
+    utility::print_callback,
 };
-use crate::{context::Context, string_ref::StringRef, utility::print_callback};
 use distinct::DisctinctAttribute;
 use mlir_sys::{
     MlirAttribute, mlirAttributeEqual, mlirAttributeGetNull, mlirAttributeParseGet,
@@ -55,11 +73,41 @@ impl<'c> Attribute<'c> {
         }
     }
 
+    /// Parses an attribute returning diagnostics on failure.
+    pub fn parse_with_diagnostics(
+        context: &'c Context,
+        source: &str,
+    ) -> Result<Self, Vec<OwnedDiagnostic<'c>>> {
+        match collect_diagnostics(context, || Self::parse(context, source)) {
+            (Some(attribute), _) => Ok(attribute),
+            (None, diagnostics) => Err(diagnostics),
+        }
+    }
+
     /// Creates a unit attribute.
     pub fn unit(context: &'c Context) -> Self {
         unsafe { Self::from_raw(mlirUnitAttrGet(context.to_raw())) }
     }
 
+    /// Returns `true` if an attribute is an instance of `T`.
+    pub fn isa<T: Downcast<Self>>(&self) -> bool {
+        T::isa(self)
+    }
+
+    /// Downcasts an attribute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an attribute is not an instance of `T`.
+    pub fn cast<T: Downcast<Self>>(self) -> T {
+        T::cast(self)
+    }
+
+    /// Downcasts an attribute if it is an instance of `T`.
+    pub fn dyn_cast<T: Downcast<Self>>(self) -> Option<T> {
+        T::dyn_cast(self).ok()
+    }
+
     pub(crate) unsafe fn null() -> Self {
         unsafe { Self::from_raw(mlirAttributeGetNull()) }
     }
@@ -135,18 +183,26 @@ impl std::hash::Hash for Attribute<'_> {
 
 from_subtypes!(
     Attribute,
+    AffineMapAttribute,
     ArrayAttribute,
     BoolAttribute,
     DenseElementsAttribute,
     DenseI32ArrayAttribute,
     DenseI64ArrayAttribute,
+    DenseResourceElementsAttribute,
     DictionaryAttribute,
     FlatSymbolRefAttribute,
     FloatAttribute,
     IntegerAttribute,
+    IntegerSetAttribute,
+    LocationAttribute,
+    OpaqueAttribute,
+    SparseElementsAttribute,
     StringAttribute,
     StridedLayoutAttribute,
+    SymbolRefAttribute,
     TypeAttribute,
+    UnitAttribute,
     DisctinctAttribute,
 );
 
@@ -175,6 +231,18 @@ mod tests {
         assert!(Attribute::parse(&Context::new(), "z").is_none());
     }
 
+    #[test]
+    fn parse_with_diagnostics() {
+        let context = Context::new();
+
+        assert!(Attribute::parse_with_diagnostics(&context, "unit").is_ok());
+
+        let diagnostics = Attribute::parse_with_diagnostics(&context, "z").unwrap_err();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message(), "expected attribute value");
+    }
+
     #[test]
     fn context() {
         let context = create_test_context();
@@ -0,0 +1,37 @@
+use super::{Attribute, AttributeLike};
+use crate::{Error, ir::AffineMap};
+use mlir_sys::{MlirAttribute, mlirAffineMapAttrGet, mlirAffineMapAttrGetValue};
+
+/// An affine map attribute.
+#[derive(Clone, Copy, Hash)]
+pub struct AffineMapAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> AffineMapAttribute<'c> {
+    /// Creates an affine map attribute.
+    pub fn new(map: AffineMap<'c>) -> Self {
+        unsafe { Self::from_raw(mlirAffineMapAttrGet(map.to_raw())) }
+    }
+
+    /// Returns an affine map.
+    pub fn value(&self) -> AffineMap<'c> {
+        unsafe { AffineMap::from_raw(mlirAffineMapAttrGetValue(self.to_raw())) }
+    }
+}
+
+attribute_traits!(AffineMapAttribute, is_affine_map, "affine map");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    #[test]
+    fn value() {
+        let context = create_test_context();
+        let map = AffineMap::multi_dim_identity(&context, 2);
+
+        assert_eq!(AffineMapAttribute::new(map).value(), map);
+    }
+}
@@ -47,12 +47,14 @@ pub trait AttributeLike<'c> {
         mlirAttributeIsADenseI64Array,
         mlirAttributeIsADenseI8Array,
         mlirAttributeIsADenseIntElements,
+        mlirAttributeIsADenseResourceElements,
         mlirAttributeIsADictionary,
         mlirAttributeIsAElements,
         mlirAttributeIsAFlatSymbolRef,
         mlirAttributeIsAFloat,
         mlirAttributeIsAInteger,
         mlirAttributeIsAIntegerSet,
+        mlirAttributeIsALocation,
         mlirAttributeIsAOpaque,
         mlirAttributeIsASparseElements,
         mlirAttributeIsASparseTensorEncodingAttr,
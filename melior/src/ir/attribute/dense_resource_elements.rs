@@ -0,0 +1,341 @@
+use super::{Attribute, AttributeLike};
+use crate::{
+    Error, StringRef,
+    ir::{
+        Type, TypeLike,
+        r#type::{FloatType, IntegerType},
+    },
+};
+use mlir_sys::{
+    MlirAttribute, mlirDenseBoolResourceElementsAttrGetValue,
+    mlirDenseDoubleResourceElementsAttrGetValue, mlirDenseFloatResourceElementsAttrGetValue,
+    mlirDenseInt8ResourceElementsAttrGetValue, mlirDenseInt16ResourceElementsAttrGetValue,
+    mlirDenseInt32ResourceElementsAttrGetValue, mlirDenseInt64ResourceElementsAttrGetValue,
+    mlirDenseUInt8ResourceElementsAttrGetValue, mlirDenseUInt16ResourceElementsAttrGetValue,
+    mlirDenseUInt32ResourceElementsAttrGetValue, mlirDenseUInt64ResourceElementsAttrGetValue,
+    mlirElementsAttrGetNumElements, mlirShapedTypeGetDimSize, mlirShapedTypeGetElementType,
+    mlirShapedTypeGetRank, mlirShapedTypeHasStaticShape, mlirUnmanagedDenseResourceElementsAttrGet,
+};
+use std::{
+    alloc::{Layout, alloc, dealloc, handle_alloc_error},
+    ffi::c_void,
+    ptr::copy_nonoverlapping,
+};
+
+macro_rules! resource_element_accessor {
+    ($name:ident, $type:ty, $type_name:expr, $check:expr, $ffi:ident) => {
+        #[doc = concat!("Returns a `", stringify!($type), "` element at the given index.")]
+        pub fn $name(&self, index: usize) -> Result<$type, Error> {
+            let check: fn(Type<'c>) -> bool = $check;
+
+            if !check(self.element_type()) {
+                Err(Error::ElementExpected {
+                    r#type: $type_name,
+                    value: self.to_string(),
+                })
+            } else if index < self.len() {
+                Ok(unsafe { $ffi(self.attribute.to_raw(), index as isize) })
+            } else {
+                Err(Error::PositionOutOfBounds {
+                    name: "dense resource element",
+                    value: self.to_string(),
+                    index,
+                })
+            }
+        }
+    };
+}
+
+/// A dense resource elements attribute.
+#[derive(Clone, Copy, Hash)]
+pub struct DenseResourceElementsAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> DenseResourceElementsAttribute<'c> {
+    /// Creates a dense resource elements attribute.
+    ///
+    /// Data is copied into a resource blob named `name` and aligned to
+    /// `alignment` bytes. `type` must be statically shaped with an integer,
+    /// index, or floating-point element type. `data` must contain all of its
+    /// elements and `alignment` must be a power of two and a multiple of
+    /// alignment of its elements.
+    pub fn new(r#type: Type<'c>, name: &str, data: &[u8], alignment: usize) -> Result<Self, Error> {
+        if !r#type.is_shaped() || !unsafe { mlirShapedTypeHasStaticShape(r#type.to_raw()) } {
+            return Err(Error::TypeExpected("statically shaped", r#type.to_string()));
+        }
+
+        let element_type = unsafe { Type::from_raw(mlirShapedTypeGetElementType(r#type.to_raw())) };
+        let Some(element_size) = element_size(element_type) else {
+            return Err(Error::TypeExpected(
+                "integer, index, or floating-point",
+                element_type.to_string(),
+            ));
+        };
+        let size = (0..unsafe { mlirShapedTypeGetRank(r#type.to_raw()) })
+            .map(|index| unsafe { mlirShapedTypeGetDimSize(r#type.to_raw(), index) } as usize)
+            .product::<usize>()
+            * element_size;
+
+        if data.len() != size {
+            return Err(Error::DataSizeMismatch {
+                expected: size,
+                actual: data.len(),
+            });
+        } else if !alignment.is_power_of_two() || alignment % element_size.next_power_of_two() != 0
+        {
+            return Err(Error::AlignmentMismatch {
+                expected: element_size.next_power_of_two(),
+                actual: alignment,
+            });
+        }
+
+        let layout = Self::layout(data.len(), alignment);
+        let pointer = unsafe { alloc(layout) };
+
+        if pointer.is_null() {
+            handle_alloc_error(layout);
+        }
+
+        unsafe { copy_nonoverlapping(data.as_ptr(), pointer, data.len()) };
+
+        Ok(unsafe {
+            Self::from_raw(mlirUnmanagedDenseResourceElementsAttrGet(
+                r#type.to_raw(),
+                StringRef::new(name).to_raw(),
+                pointer as *mut c_void,
+                data.len(),
+                alignment,
+                false,
+                Some(Self::delete),
+                std::ptr::null_mut(),
+            ))
+        })
+    }
+
+    /// Returns a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirElementsAttrGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if an array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    resource_element_accessor!(
+        bool_element,
+        bool,
+        "signless i1",
+        |r#type| IntegerType::try_from(r#type)
+            .map(|r#type| r#type.is_signless() && r#type.width() == 1)
+            .unwrap_or_default(),
+        mlirDenseBoolResourceElementsAttrGetValue
+    );
+    resource_element_accessor!(
+        i8_element,
+        i8,
+        "signed or signless i8",
+        |r#type| integer_width(r#type, false) == Some(8),
+        mlirDenseInt8ResourceElementsAttrGetValue
+    );
+    resource_element_accessor!(
+        i16_element,
+        i16,
+        "signed or signless i16",
+        |r#type| integer_width(r#type, false) == Some(16),
+        mlirDenseInt16ResourceElementsAttrGetValue
+    );
+    resource_element_accessor!(
+        i32_element,
+        i32,
+        "signed or signless i32",
+        |r#type| integer_width(r#type, false) == Some(32),
+        mlirDenseInt32ResourceElementsAttrGetValue
+    );
+    resource_element_accessor!(
+        i64_element,
+        i64,
+        "signed or signless i64",
+        |r#type| integer_width(r#type, false) == Some(64),
+        mlirDenseInt64ResourceElementsAttrGetValue
+    );
+    resource_element_accessor!(
+        u8_element,
+        u8,
+        "unsigned or signless i8",
+        |r#type| integer_width(r#type, true) == Some(8),
+        mlirDenseUInt8ResourceElementsAttrGetValue
+    );
+    resource_element_accessor!(
+        u16_element,
+        u16,
+        "unsigned or signless i16",
+        |r#type| integer_width(r#type, true) == Some(16),
+        mlirDenseUInt16ResourceElementsAttrGetValue
+    );
+    resource_element_accessor!(
+        u32_element,
+        u32,
+        "unsigned or signless i32",
+        |r#type| integer_width(r#type, true) == Some(32),
+        mlirDenseUInt32ResourceElementsAttrGetValue
+    );
+    resource_element_accessor!(
+        u64_element,
+        u64,
+        "unsigned or signless i64",
+        |r#type| integer_width(r#type, true) == Some(64),
+        mlirDenseUInt64ResourceElementsAttrGetValue
+    );
+    resource_element_accessor!(
+        f32_element,
+        f32,
+        "f32",
+        |r#type| r#type.is_f32(),
+        mlirDenseFloatResourceElementsAttrGetValue
+    );
+    resource_element_accessor!(
+        f64_element,
+        f64,
+        "f64",
+        |r#type| r#type.is_f64(),
+        mlirDenseDoubleResourceElementsAttrGetValue
+    );
+
+    fn element_type(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirShapedTypeGetElementType(self.r#type().to_raw())) }
+    }
+
+    fn layout(size: usize, alignment: usize) -> Layout {
+        // Allocations of zero bytes are not allowed.
+        Layout::from_size_align(size.max(1), alignment).expect("valid alignment")
+    }
+
+    unsafe extern "C" fn delete(
+        _user_data: *mut c_void,
+        data: *const c_void,
+        size: usize,
+        alignment: usize,
+    ) {
+        unsafe { dealloc(data as *mut u8, Self::layout(size, alignment)) }
+    }
+}
+
+attribute_traits!(
+    DenseResourceElementsAttribute,
+    is_dense_resource_elements,
+    "dense resource elements"
+);
+
+// Returns a size in bytes of an element in resource blobs.
+fn element_size(r#type: Type) -> Option<usize> {
+    let width = if let Ok(r#type) = IntegerType::try_from(r#type) {
+        r#type.width()
+    } else if r#type.is_index() {
+        64
+    } else {
+        FloatType::try_from(r#type).ok()?.width()
+    };
+
+    Some(width.div_ceil(8) as usize)
+}
+
+// Returns a width of an integer type accepted by resource element accessors
+// of the given signedness. Signless integers are accepted by both.
+fn integer_width(r#type: Type, unsigned: bool) -> Option<u32> {
+    let r#type = IntegerType::try_from(r#type).ok()?;
+
+    if r#type.is_signless() || r#type.is_unsigned() == unsigned {
+        Some(r#type.width())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::r#type::RankedTensorType, test::create_test_context};
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(&[2], IntegerType::new(&context, 32).into(), None);
+        let data = [1i32, 2]
+            .iter()
+            .flat_map(|value| value.to_ne_bytes())
+            .collect::<Vec<_>>();
+        let attribute =
+            DenseResourceElementsAttribute::new(r#type.into(), "foo", &data, 4).unwrap();
+
+        assert_eq!(attribute.len(), 2);
+        assert_eq!(attribute.i32_element(0), Ok(1));
+        assert_eq!(attribute.i32_element(1), Ok(2));
+        assert_eq!(attribute.u32_element(1), Ok(2));
+        assert!(attribute.i32_element(2).is_err());
+        assert!(attribute.i64_element(0).is_err());
+        assert!(attribute.f32_element(0).is_err());
+    }
+
+    #[test]
+    fn new_non_shaped() {
+        let context = create_test_context();
+
+        assert!(DenseResourceElementsAttribute::new(Type::index(&context), "foo", &[], 1).is_err());
+    }
+
+    #[test]
+    fn new_with_invalid_data_size() {
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(&[4], IntegerType::new(&context, 32).into(), None);
+
+        assert_eq!(
+            DenseResourceElementsAttribute::new(r#type.into(), "foo", &[0; 4], 4).err(),
+            Some(Error::DataSizeMismatch {
+                expected: 16,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn new_with_invalid_alignment() {
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(&[2], IntegerType::new(&context, 64).into(), None);
+
+        assert_eq!(
+            DenseResourceElementsAttribute::new(r#type.into(), "foo", &[0; 16], 4).err(),
+            Some(Error::AlignmentMismatch {
+                expected: 8,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn new_with_non_power_of_two_alignment() {
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(&[2], IntegerType::new(&context, 32).into(), None);
+
+        assert_eq!(
+            DenseResourceElementsAttribute::new(r#type.into(), "foo", &[0; 8], 12).err(),
+            Some(Error::AlignmentMismatch {
+                expected: 4,
+                actual: 12
+            })
+        );
+    }
+
+    #[test]
+    fn new_with_dynamic_shape() {
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(
+            &[i64::MIN as u64],
+            IntegerType::new(&context, 32).into(),
+            None,
+        );
+
+        assert!(DenseResourceElementsAttribute::new(r#type.into(), "foo", &[], 4).is_err());
+    }
+}
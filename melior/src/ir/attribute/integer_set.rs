@@ -0,0 +1,37 @@
+use super::{Attribute, AttributeLike};
+use crate::{Error, ir::IntegerSet};
+use mlir_sys::{MlirAttribute, mlirIntegerSetAttrGet, mlirIntegerSetAttrGetValue};
+
+/// An integer set attribute.
+#[derive(Clone, Copy, Hash)]
+pub struct IntegerSetAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> IntegerSetAttribute<'c> {
+    /// Creates an integer set attribute.
+    pub fn new(set: IntegerSet<'c>) -> Self {
+        unsafe { Self::from_raw(mlirIntegerSetAttrGet(set.to_raw())) }
+    }
+
+    /// Returns an integer set.
+    pub fn value(&self) -> IntegerSet<'c> {
+        unsafe { IntegerSet::from_raw(mlirIntegerSetAttrGetValue(self.to_raw())) }
+    }
+}
+
+attribute_traits!(IntegerSetAttribute, is_integer_set, "integer set");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    #[test]
+    fn value() {
+        let context = create_test_context();
+        let set = IntegerSet::empty(&context, 1, 0);
+
+        assert_eq!(IntegerSetAttribute::new(set).value(), set);
+    }
+}
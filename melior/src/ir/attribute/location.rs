@@ -0,0 +1,54 @@
+use super::{Attribute, AttributeLike};
+use crate::{Error, ir::Location};
+use mlir_sys::MlirAttribute;
+
+/// A location attribute.
+#[derive(Clone, Copy, Hash)]
+pub struct LocationAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> LocationAttribute<'c> {
+    /// Creates a location attribute.
+    pub fn new(location: Location<'c>) -> Self {
+        Self {
+            attribute: location.to_attribute(),
+        }
+    }
+
+    /// Returns a location.
+    pub fn value(&self) -> Location<'c> {
+        Location::from_attribute(self.attribute)
+    }
+}
+
+impl<'c> From<Location<'c>> for LocationAttribute<'c> {
+    fn from(location: Location<'c>) -> Self {
+        Self::new(location)
+    }
+}
+
+attribute_traits!(LocationAttribute, is_location, "location");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    #[test]
+    fn value() {
+        let context = create_test_context();
+        let location = Location::new(&context, "foo", 1, 2);
+
+        assert_eq!(LocationAttribute::new(location).value(), location);
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+        let location = Location::new(&context, "foo", 1, 2);
+
+        assert!(LocationAttribute::try_from(location.to_attribute()).is_ok());
+        assert!(LocationAttribute::try_from(Attribute::unit(&context)).is_err());
+    }
+}
@@ -22,6 +22,16 @@ macro_rules! attribute_traits {
             }
         }
 
+        impl<'c> crate::ir::Downcast<crate::ir::attribute::Attribute<'c>> for $name<'c> {
+            fn isa(attribute: &crate::ir::attribute::Attribute<'c>) -> bool {
+                attribute.$is_type()
+            }
+
+            unsafe fn cast_unchecked(attribute: crate::ir::attribute::Attribute<'c>) -> Self {
+                unsafe { Self::from_raw(attribute.to_raw()) }
+            }
+        }
+
         impl<'c> crate::ir::attribute::AttributeLike<'c> for $name<'c> {
             fn to_raw(&self) -> mlir_sys::MlirAttribute {
                 self.attribute.to_raw()
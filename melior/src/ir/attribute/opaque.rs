@@ -0,0 +1,71 @@
+use super::{Attribute, AttributeLike};
+use crate::{
+    Context, Error, StringRef,
+    ir::{Type, TypeLike},
+};
+use mlir_sys::{
+    MlirAttribute, mlirOpaqueAttrGet, mlirOpaqueAttrGetData, mlirOpaqueAttrGetDialectNamespace,
+};
+use std::slice;
+
+/// An opaque attribute.
+#[derive(Clone, Copy, Hash)]
+pub struct OpaqueAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> OpaqueAttribute<'c> {
+    /// Creates an opaque attribute.
+    pub fn new(
+        context: &'c Context,
+        dialect_namespace: &str,
+        data: &[u8],
+        r#type: Type<'c>,
+    ) -> Self {
+        unsafe {
+            Self::from_raw(mlirOpaqueAttrGet(
+                context.to_raw(),
+                StringRef::new(dialect_namespace).to_raw(),
+                data.len() as isize,
+                data.as_ptr() as *const _,
+                r#type.to_raw(),
+            ))
+        }
+    }
+
+    /// Returns a dialect namespace.
+    pub fn dialect_namespace(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirOpaqueAttrGetDialectNamespace(self.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns data.
+    pub fn data(&self) -> &'c [u8] {
+        unsafe {
+            let data = mlirOpaqueAttrGetData(self.to_raw());
+
+            slice::from_raw_parts(data.data as *const u8, data.length)
+        }
+    }
+}
+
+attribute_traits!(OpaqueAttribute, is_opaque, "opaque");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let r#type = Type::index(&context);
+        let attribute = OpaqueAttribute::new(&context, "foo", b"bar", r#type);
+
+        assert_eq!(attribute.dialect_namespace(), "foo");
+        assert_eq!(attribute.data(), b"bar");
+        assert_eq!(attribute.r#type(), r#type);
+    }
+}
@@ -0,0 +1,188 @@
+use super::{Attribute, AttributeLike, DenseElementsAttribute};
+use crate::{
+    Error,
+    ir::{Type, TypeLike, r#type::IntegerType},
+};
+use mlir_sys::{
+    MlirAttribute, mlirShapedTypeGetElementType, mlirShapedTypeGetRank, mlirShapedTypeHasRank,
+    mlirSparseElementsAttrGetIndices, mlirSparseElementsAttrGetValues, mlirSparseElementsAttribute,
+};
+
+/// A sparse elements attribute.
+#[derive(Clone, Copy, Hash)]
+pub struct SparseElementsAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> SparseElementsAttribute<'c> {
+    /// Creates a sparse elements attribute.
+    ///
+    /// `indices` is a 2-D tensor of 64-bit integer indices of non-zero
+    /// elements, and `values` is a 1-D tensor of their values.
+    pub fn new(
+        r#type: Type<'c>,
+        indices: DenseElementsAttribute<'c>,
+        values: DenseElementsAttribute<'c>,
+    ) -> Result<Self, Error> {
+        let index_type =
+            unsafe { Type::from_raw(mlirShapedTypeGetElementType(indices.r#type().to_raw())) };
+
+        if !r#type.is_shaped() {
+            return Err(Error::TypeExpected("shaped", r#type.to_string()));
+        } else if rank(indices.r#type()) != Some(2)
+            || IntegerType::try_from(index_type).map(|r#type| r#type.width()) != Ok(64)
+        {
+            return Err(Error::AttributeExpected(
+                "2-D i64 dense elements",
+                indices.to_string(),
+            ));
+        } else if rank(values.r#type()) != Some(1) {
+            return Err(Error::AttributeExpected(
+                "1-D dense elements",
+                values.to_string(),
+            ));
+        }
+
+        Ok(unsafe {
+            Self::from_raw(mlirSparseElementsAttribute(
+                r#type.to_raw(),
+                indices.to_raw(),
+                values.to_raw(),
+            ))
+        })
+    }
+
+    /// Returns indices of non-zero elements.
+    pub fn indices(&self) -> DenseElementsAttribute<'c> {
+        unsafe { Attribute::from_raw(mlirSparseElementsAttrGetIndices(self.to_raw())) }
+            .try_into()
+            .expect("dense elements attribute")
+    }
+
+    /// Returns values of non-zero elements.
+    pub fn values(&self) -> DenseElementsAttribute<'c> {
+        unsafe { Attribute::from_raw(mlirSparseElementsAttrGetValues(self.to_raw())) }
+            .try_into()
+            .expect("dense elements attribute")
+    }
+}
+
+attribute_traits!(
+    SparseElementsAttribute,
+    is_sparse_elements,
+    "sparse elements"
+);
+
+fn rank(r#type: Type) -> Option<isize> {
+    unsafe { mlirShapedTypeHasRank(r#type.to_raw()) }
+        .then(|| unsafe { mlirShapedTypeGetRank(r#type.to_raw()) } as isize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            attribute::IntegerAttribute,
+            r#type::{IntegerType, RankedTensorType},
+        },
+        test::create_test_context,
+    };
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let i64_type = IntegerType::new(&context, 64).into();
+        let indices = DenseElementsAttribute::new(
+            RankedTensorType::new(&[1, 2], i64_type, None).into(),
+            &[
+                IntegerAttribute::new(i64_type, 0).into(),
+                IntegerAttribute::new(i64_type, 1).into(),
+            ],
+        )
+        .unwrap();
+        let values = DenseElementsAttribute::new(
+            RankedTensorType::new(&[1], i64_type, None).into(),
+            &[IntegerAttribute::new(i64_type, 42).into()],
+        )
+        .unwrap();
+        let attribute = SparseElementsAttribute::new(
+            RankedTensorType::new(&[2, 2], i64_type, None).into(),
+            indices,
+            values,
+        )
+        .unwrap();
+
+        assert_eq!(attribute.indices().to_string(), indices.to_string());
+        assert_eq!(attribute.values().to_string(), values.to_string());
+        assert!(SparseElementsAttribute::new(i64_type, indices, values).is_err());
+    }
+
+    #[test]
+    fn new_with_invalid_indices() {
+        let context = create_test_context();
+        let i32_type = IntegerType::new(&context, 32).into();
+        let i64_type = IntegerType::new(&context, 64).into();
+        let r#type = RankedTensorType::new(&[2, 2], i64_type, None).into();
+        let values = DenseElementsAttribute::new(
+            RankedTensorType::new(&[1], i64_type, None).into(),
+            &[IntegerAttribute::new(i64_type, 42).into()],
+        )
+        .unwrap();
+
+        for indices in [
+            DenseElementsAttribute::new(
+                RankedTensorType::new(&[2], i64_type, None).into(),
+                &[
+                    IntegerAttribute::new(i64_type, 0).into(),
+                    IntegerAttribute::new(i64_type, 1).into(),
+                ],
+            )
+            .unwrap(),
+            DenseElementsAttribute::new(
+                RankedTensorType::new(&[1, 2], i32_type, None).into(),
+                &[
+                    IntegerAttribute::new(i32_type, 0).into(),
+                    IntegerAttribute::new(i32_type, 1).into(),
+                ],
+            )
+            .unwrap(),
+        ] {
+            assert_eq!(
+                SparseElementsAttribute::new(r#type, indices, values).err(),
+                Some(Error::AttributeExpected(
+                    "2-D i64 dense elements",
+                    indices.to_string()
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn new_with_invalid_values() {
+        let context = create_test_context();
+        let i64_type = IntegerType::new(&context, 64).into();
+        let indices = DenseElementsAttribute::new(
+            RankedTensorType::new(&[1, 2], i64_type, None).into(),
+            &[
+                IntegerAttribute::new(i64_type, 0).into(),
+                IntegerAttribute::new(i64_type, 1).into(),
+            ],
+        )
+        .unwrap();
+        let values = DenseElementsAttribute::new(
+            RankedTensorType::new(&[1, 1], i64_type, None).into(),
+            &[IntegerAttribute::new(i64_type, 42).into()],
+        )
+        .unwrap();
+
+        assert!(
+            SparseElementsAttribute::new(
+                RankedTensorType::new(&[2, 2], i64_type, None).into(),
+                indices,
+                values
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use super::{Attribute, AttributeLike, FlatSymbolRefAttribute};
+use crate::{Context, Error, StringRef};
+use mlir_sys::{
+    MlirAttribute, mlirSymbolRefAttrGet, mlirSymbolRefAttrGetLeafReference,
+    mlirSymbolRefAttrGetNestedReference, mlirSymbolRefAttrGetNumNestedReferences,
+    mlirSymbolRefAttrGetRootReference,
+};
+
+/// A symbol ref attribute.
+#[derive(Clone, Copy, Hash)]
+pub struct SymbolRefAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> SymbolRefAttribute<'c> {
+    /// Creates a symbol ref attribute.
+    pub fn new(
+        context: &'c Context,
+        symbol: &str,
+        references: &[FlatSymbolRefAttribute<'c>],
+    ) -> Self {
+        let references = references
+            .iter()
+            .map(|reference| reference.to_raw())
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Self::from_raw(mlirSymbolRefAttrGet(
+                context.to_raw(),
+                StringRef::new(symbol).to_raw(),
+                references.len() as isize,
+                references.as_ptr(),
+            ))
+        }
+    }
+
+    /// Returns a root reference.
+    pub fn root_reference(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirSymbolRefAttrGetRootReference(self.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns a leaf reference.
+    pub fn leaf_reference(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirSymbolRefAttrGetLeafReference(self.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns a number of nested references.
+    pub fn nested_reference_count(&self) -> usize {
+        unsafe { mlirSymbolRefAttrGetNumNestedReferences(self.to_raw()) as usize }
+    }
+
+    /// Returns a nested reference.
+    pub fn nested_reference(&self, index: usize) -> Result<FlatSymbolRefAttribute<'c>, Error> {
+        if index < self.nested_reference_count() {
+            unsafe {
+                Attribute::from_raw(mlirSymbolRefAttrGetNestedReference(
+                    self.to_raw(),
+                    index as isize,
+                ))
+            }
+            .try_into()
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "symbol ref nested reference",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+}
+
+attribute_traits!(SymbolRefAttribute, is_symbol_ref, "symbol ref");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let attribute = SymbolRefAttribute::new(
+            &context,
+            "foo",
+            &[
+                FlatSymbolRefAttribute::new(&context, "bar"),
+                FlatSymbolRefAttribute::new(&context, "baz"),
+            ],
+        );
+
+        assert_eq!(attribute.root_reference(), "foo");
+        assert_eq!(attribute.leaf_reference(), "baz");
+        assert_eq!(attribute.nested_reference_count(), 2);
+        assert_eq!(attribute.nested_reference(0).unwrap().value(), "bar");
+        assert!(attribute.nested_reference(2).is_err());
+    }
+}
@@ -325,6 +325,59 @@ mod tests {
         assert_eq!(block.first_operation(), None);
     }
 
+    #[test]
+    fn operations() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+
+        let operations = ["foo", "bar", "baz"].map(|name| {
+            block.append_operation(OperationBuilder::new(name, location).build().unwrap())
+        });
+
+        assert_eq!(block.operations().collect::<Vec<_>>(), operations);
+        assert_eq!(
+            block.operations().rev().collect::<Vec<_>>(),
+            operations.into_iter().rev().collect::<Vec<_>>()
+        );
+        assert_eq!(block.operations_mut().count(), 3);
+    }
+
+    #[test]
+    fn operations_empty() {
+        assert_eq!(Block::new(&[]).operations().next_back(), None);
+    }
+
+    #[test]
+    fn arguments() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+        let integer_type = IntegerType::new(&context, 64).into();
+        let block = Block::new(&[(index_type, location), (integer_type, location)]);
+
+        assert_eq!(
+            block
+                .arguments()
+                .rev()
+                .map(|argument| argument.r#type())
+                .collect::<Vec<_>>(),
+            [integer_type, index_type]
+        );
+    }
+
+    #[test]
+    fn previous_in_region() {
+        let region = Region::new();
+        let first = region.append_block(Block::new(&[]));
+        let second = region.append_block(Block::new(&[]));
+
+        assert_eq!(first.previous_in_region(), None);
+        assert_eq!(second.previous_in_region(), Some(first));
+        assert_eq!(Block::new(&[]).previous_in_region(), None);
+    }
+
     #[test]
     fn append_operation() {
         let context = create_test_context();
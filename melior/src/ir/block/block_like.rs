@@ -1,7 +1,10 @@
 use super::{BlockArgument, BlockRef, TypeLike};
 use crate::{
     Error,
-    ir::{Location, Operation, OperationRef, RegionRef, Type, Value, operation::OperationRefMut},
+    ir::{
+        IrMapping, Location, Operation, OperationRef, RegionLike, RegionRef, Type, Value,
+        list_iterator::ListIterator, operation::OperationRefMut,
+    },
 };
 use core::fmt::Display;
 use mlir_sys::{
@@ -11,7 +14,7 @@ use mlir_sys::{
     mlirBlockGetParentOperation, mlirBlockGetParentRegion, mlirBlockGetPredecessor,
     mlirBlockGetSuccessor, mlirBlockGetTerminator, mlirBlockInsertArgument,
     mlirBlockInsertOwnedOperation, mlirBlockInsertOwnedOperationAfter,
-    mlirBlockInsertOwnedOperationBefore,
+    mlirBlockInsertOwnedOperationBefore, mlirOperationGetNextInBlock,
 };
 
 /// A trait for block-like types.
@@ -43,6 +46,11 @@ pub trait BlockLike<'c, 'a>: Display + 'a {
         unsafe { mlirBlockGetNumArguments(self.to_raw()) as usize }
     }
 
+    /// Returns all arguments.
+    fn arguments(&self) -> impl DoubleEndedIterator<Item = BlockArgument<'c, 'a>> {
+        (0..self.argument_count()).map(|index| self.argument(index).expect("valid argument index"))
+    }
+
     /// Returns a reference to the first operation.
     fn first_operation(&self) -> Option<OperationRef<'c, 'a>> {
         unsafe { OperationRef::from_option_raw(mlirBlockGetFirstOperation(self.to_raw())) }
@@ -53,6 +61,25 @@ pub trait BlockLike<'c, 'a>: Display + 'a {
         unsafe { OperationRefMut::from_option_raw(mlirBlockGetFirstOperation(self.to_raw())) }
     }
 
+    /// Returns references to all operations.
+    fn operations(&self) -> impl DoubleEndedIterator<Item = OperationRef<'c, 'a>> {
+        ListIterator::new(
+            self.first_operation().map(|operation| operation.to_raw()),
+            |operation| {
+                let operation = unsafe { mlirOperationGetNextInBlock(operation) };
+
+                (!operation.ptr.is_null()).then_some(operation)
+            },
+        )
+        .map(|operation| unsafe { OperationRef::from_raw(operation) })
+    }
+
+    /// Returns mutable references to all operations.
+    fn operations_mut(&self) -> impl DoubleEndedIterator<Item = OperationRefMut<'c, 'a>> {
+        self.operations()
+            .map(|operation| unsafe { OperationRefMut::from_raw(operation.to_raw()) })
+    }
+
     /// Returns a reference to a terminator operation.
     fn terminator(&self) -> Option<OperationRef<'c, 'a>> {
         unsafe { OperationRef::from_option_raw(mlirBlockGetTerminator(self.to_raw())) }
@@ -213,4 +240,29 @@ pub trait BlockLike<'c, 'a>: Display + 'a {
     fn next_in_region(&self) -> Option<BlockRef<'c, 'a>> {
         unsafe { BlockRef::from_option_raw(mlirBlockGetNextInRegion(self.to_raw())) }
     }
+
+    /// Returns a previous block in a region in O(n) time.
+    ///
+    /// This function scans a region from its first block as the C API does
+    /// not expose backward links of blocks. Use
+    /// [`RegionLike::blocks`](crate::ir::RegionLike::blocks) with
+    /// [`Iterator::rev`] to iterate over blocks backward.
+    fn previous_in_region(&self) -> Option<BlockRef<'c, 'a>> {
+        self.parent_region()?
+            .blocks()
+            .take_while(|block| block.to_raw().ptr != self.to_raw().ptr)
+            .last()
+    }
+
+    /// Clones a block at the end of a region with a mapping.
+    ///
+    /// Block arguments already mapped in a mapping are not added to the cloned
+    /// block and their uses are replaced with the mapped values.
+    fn clone_into_region(
+        &self,
+        region: &impl RegionLike<'c, 'a>,
+        mapping: &mut IrMapping<'c, 'a>,
+    ) -> BlockRef<'c, 'a> {
+        mapping.clone_blocks(&[self.to_raw()], region)[0]
+    }
 }
@@ -0,0 +1,98 @@
+/// A trait for objects downcastable from generic ones.
+///
+/// It is implemented by typed types, attributes and operations for [`Type`],
+/// [`Attribute`] and [`Operation`] respectively, and by references to typed
+/// operations for [`OperationRef`].
+///
+/// [`Type`]: super::Type
+/// [`Attribute`]: super::Attribute
+/// [`Operation`]: super::Operation
+/// [`OperationRef`]: super::OperationRef
+pub trait Downcast<T>: Sized {
+    /// Returns `true` if an object is an instance of `Self`.
+    fn isa(object: &T) -> bool;
+
+    /// Downcasts an object without checking its kind.
+    ///
+    /// # Safety
+    ///
+    /// An object must be an instance of `Self`.
+    unsafe fn cast_unchecked(object: T) -> Self;
+
+    /// Downcasts an object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an object is not an instance of `Self`.
+    fn cast(object: T) -> Self {
+        assert!(Self::isa(&object), "invalid downcast");
+
+        unsafe { Self::cast_unchecked(object) }
+    }
+
+    /// Downcasts an object if it is an instance of `Self`.
+    ///
+    /// It returns the object back otherwise.
+    fn dyn_cast(object: T) -> Result<Self, T> {
+        if Self::isa(&object) {
+            Ok(unsafe { Self::cast_unchecked(object) })
+        } else {
+            Err(object)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Context,
+        ir::{
+            Attribute, Type,
+            attribute::{IntegerAttribute, StringAttribute},
+            r#type::{FunctionType, IntegerType},
+        },
+    };
+
+    fn count<'c, T: Downcast<Type<'c>>>(types: &[Type<'c>]) -> usize {
+        types.iter().filter(|r#type| T::isa(r#type)).count()
+    }
+
+    #[test]
+    fn isa() {
+        let context = Context::new();
+        let types = [
+            Type::index(&context),
+            IntegerType::new(&context, 64).into(),
+            IntegerType::new(&context, 32).into(),
+        ];
+
+        assert_eq!(count::<IntegerType>(&types), 2);
+        assert_eq!(count::<FunctionType>(&types), 0);
+    }
+
+    #[test]
+    fn cast() {
+        let context = Context::new();
+        let attribute = Attribute::parse(&context, "42 : i64").unwrap();
+
+        assert_eq!(IntegerAttribute::cast(attribute).value(), 42);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cast_fail() {
+        let context = Context::new();
+
+        StringAttribute::cast(Attribute::parse(&context, "42 : i64").unwrap());
+    }
+
+    #[test]
+    fn dyn_cast() {
+        let context = Context::new();
+        let attribute = Attribute::parse(&context, "42 : i64").unwrap();
+
+        assert!(IntegerAttribute::dyn_cast(attribute).is_ok());
+        assert_eq!(StringAttribute::dyn_cast(attribute).err(), Some(attribute));
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{
+    Error,
+    context::{Context, ContextRef},
+    ir::AffineExpr,
+    utility::print_callback,
+};
+use mlir_sys::{
+    MlirIntegerSet, mlirIntegerSetDump, mlirIntegerSetEmptyGet, mlirIntegerSetEqual,
+    mlirIntegerSetGet, mlirIntegerSetGetConstraint, mlirIntegerSetGetContext,
+    mlirIntegerSetGetNumConstraints, mlirIntegerSetGetNumDims, mlirIntegerSetGetNumEqualities,
+    mlirIntegerSetGetNumInequalities, mlirIntegerSetGetNumInputs, mlirIntegerSetGetNumSymbols,
+    mlirIntegerSetIsCanonicalEmpty, mlirIntegerSetIsConstraintEq, mlirIntegerSetPrint,
+};
+use std::{
+    ffi::c_void,
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+};
+
+/// An integer set.
+#[derive(Clone, Copy)]
+pub struct IntegerSet<'c> {
+    raw: MlirIntegerSet,
+    _context: PhantomData<&'c Context>,
+}
+
+impl<'c> IntegerSet<'c> {
+    /// Creates an integer set with constraints.
+    ///
+    /// Each constraint is an equality `expr == 0` if its flag in `equalities`
+    /// is `true` or an inequality `expr >= 0` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numbers of constraints and equality flags differ.
+    pub fn new(
+        context: &'c Context,
+        dims: usize,
+        symbols: usize,
+        constraints: &[AffineExpr<'c>],
+        equalities: &[bool],
+    ) -> Self {
+        assert_eq!(constraints.len(), equalities.len());
+
+        let constraints = constraints
+            .iter()
+            .map(|constraint| constraint.to_raw())
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Self::from_raw(mlirIntegerSetGet(
+                context.to_raw(),
+                dims as isize,
+                symbols as isize,
+                constraints.len() as isize,
+                constraints.as_ptr(),
+                equalities.as_ptr(),
+            ))
+        }
+    }
+
+    /// Creates a canonical empty integer set with the given number of
+    /// dimensions and symbols.
+    pub fn empty(context: &'c Context, dims: usize, symbols: usize) -> Self {
+        unsafe {
+            Self::from_raw(mlirIntegerSetEmptyGet(
+                context.to_raw(),
+                dims as isize,
+                symbols as isize,
+            ))
+        }
+    }
+
+    /// Returns a context.
+    pub fn context(&self) -> ContextRef<'c> {
+        unsafe { ContextRef::from_raw(mlirIntegerSetGetContext(self.raw)) }
+    }
+
+    /// Returns the number of dimensions.
+    pub fn dim_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumDims(self.raw) as usize }
+    }
+
+    /// Returns the number of symbols.
+    pub fn symbol_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumSymbols(self.raw) as usize }
+    }
+
+    /// Returns the number of inputs (dimensions + symbols).
+    pub fn input_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumInputs(self.raw) as usize }
+    }
+
+    /// Returns the number of constraints.
+    pub fn constraint_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumConstraints(self.raw) as usize }
+    }
+
+    /// Returns the number of equality constraints.
+    pub fn equality_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumEqualities(self.raw) as usize }
+    }
+
+    /// Returns the number of inequality constraints.
+    pub fn inequality_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumInequalities(self.raw) as usize }
+    }
+
+    /// Returns the constraint at the given index.
+    pub fn constraint(&self, index: usize) -> Result<AffineExpr<'c>, Error> {
+        if index < self.constraint_count() {
+            Ok(unsafe {
+                AffineExpr::from_raw(mlirIntegerSetGetConstraint(self.raw, index as isize))
+            })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "integer set constraint",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns `true` if the constraint at the given index is an equality.
+    pub fn is_constraint_equality(&self, index: usize) -> Result<bool, Error> {
+        if index < self.constraint_count() {
+            Ok(unsafe { mlirIntegerSetIsConstraintEq(self.raw, index as isize) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "integer set constraint",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns `true` if the integer set is a canonical empty set.
+    pub fn is_canonical_empty(&self) -> bool {
+        unsafe { mlirIntegerSetIsCanonicalEmpty(self.raw) }
+    }
+
+    /// Dumps an integer set.
+    pub fn dump(&self) {
+        unsafe { mlirIntegerSetDump(self.raw) }
+    }
+
+    /// Creates an integer set from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirIntegerSet) -> Self {
+        Self {
+            raw,
+            _context: Default::default(),
+        }
+    }
+
+    /// Converts an integer set into a raw object.
+    pub const fn to_raw(self) -> MlirIntegerSet {
+        self.raw
+    }
+}
+
+impl PartialEq for IntegerSet<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { mlirIntegerSetEqual(self.raw, other.raw) }
+    }
+}
+
+impl Eq for IntegerSet<'_> {}
+
+impl Display for IntegerSet<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let mut data = (formatter, Ok(()));
+
+        unsafe {
+            mlirIntegerSetPrint(
+                self.raw,
+                Some(print_callback),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1
+    }
+}
+
+impl Debug for IntegerSet<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+        let d0 = AffineExpr::dim(&context, 0);
+        let s0 = AffineExpr::symbol(&context, 0);
+        let set = IntegerSet::new(&context, 1, 1, &[d0, s0], &[true, false]);
+
+        assert_eq!(set.dim_count(), 1);
+        assert_eq!(set.symbol_count(), 1);
+        assert_eq!(set.input_count(), 2);
+        assert_eq!(set.constraint_count(), 2);
+        assert_eq!(set.equality_count(), 1);
+        assert_eq!(set.inequality_count(), 1);
+        assert_eq!(set.constraint(0).unwrap(), d0);
+        assert!(set.is_constraint_equality(0).unwrap());
+        assert!(!set.is_constraint_equality(1).unwrap());
+        assert!(set.constraint(2).is_err());
+    }
+
+    #[test]
+    fn empty() {
+        let context = Context::new();
+        let set = IntegerSet::empty(&context, 2, 1);
+
+        assert!(set.is_canonical_empty());
+        assert_eq!(set.dim_count(), 2);
+        assert_eq!(set.symbol_count(), 1);
+    }
+
+    #[test]
+    fn equal() {
+        let context = Context::new();
+
+        assert_eq!(
+            IntegerSet::empty(&context, 1, 0),
+            IntegerSet::empty(&context, 1, 0)
+        );
+        assert_ne!(
+            IntegerSet::empty(&context, 1, 0),
+            IntegerSet::empty(&context, 2, 0)
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::VecDeque;
+
+/// A double-ended iterator over an intrusive list of IR objects, such as
+/// operations in a block or blocks in a region.
+///
+/// The C API only exposes forward links of the lists. So, on the first
+/// backward step, the iterator buffers the remaining objects to traverse them
+/// in reverse.
+pub(crate) struct ListIterator<T> {
+    front: Option<T>,
+    buffer: Option<VecDeque<T>>,
+    next: fn(T) -> Option<T>,
+}
+
+impl<T: Copy> ListIterator<T> {
+    pub fn new(first: Option<T>, next: fn(T) -> Option<T>) -> Self {
+        Self {
+            front: first,
+            buffer: None,
+            next,
+        }
+    }
+}
+
+impl<T: Copy> Iterator for ListIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if let Some(buffer) = &mut self.buffer {
+            return buffer.pop_front();
+        }
+
+        let current = self.front?;
+        self.front = (self.next)(current);
+
+        Some(current)
+    }
+}
+
+impl<T: Copy> DoubleEndedIterator for ListIterator<T> {
+    fn next_back(&mut self) -> Option<T> {
+        let next = self.next;
+        let front = &mut self.front;
+
+        self.buffer
+            .get_or_insert_with(|| {
+                let mut buffer = VecDeque::new();
+
+                while let Some(current) = *front {
+                    buffer.push_back(current);
+                    *front = next(current);
+                }
+
+                buffer
+            })
+            .pop_back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iterator() -> ListIterator<usize> {
+        ListIterator::new(Some(0), |index| (index < 3).then_some(index + 1))
+    }
+
+    #[test]
+    fn forward() {
+        assert_eq!(iterator().collect::<Vec<_>>(), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn backward() {
+        assert_eq!(iterator().rev().collect::<Vec<_>>(), [3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn both_ends() {
+        let mut iterator = iterator();
+
+        assert_eq!(iterator.next(), Some(0));
+        assert_eq!(iterator.next_back(), Some(3));
+        assert_eq!(iterator.next(), Some(1));
+        assert_eq!(iterator.next_back(), Some(2));
+        assert_eq!(iterator.next(), None);
+        assert_eq!(iterator.next_back(), None);
+    }
+}
@@ -1,5 +1,11 @@
+//! Locations.
+
+mod kind;
+
+pub use self::kind::LocationKind;
 use crate::{
     context::{Context, ContextRef},
+    diagnostic::InFlightDiagnostic,
     ir::{Attribute, AttributeLike, Identifier},
     string_ref::StringRef,
     utility::print_callback,
@@ -17,6 +23,7 @@ use mlir_sys::{
     mlirLocationNameGetName, mlirLocationPrint, mlirLocationUnknownGet,
 };
 use std::{
+    collections::HashMap,
     ffi::c_void,
     fmt::{self, Display, Formatter},
     marker::PhantomData,
@@ -121,6 +128,83 @@ impl<'c> Location<'c> {
         unsafe { mlirLocationIsAName(self.raw) }
     }
 
+    /// Returns the kind of a location with its components.
+    pub fn kind(&self) -> LocationKind<'c> {
+        if self.is_call_site() {
+            LocationKind::CallSite {
+                callee: self.call_site_callee().kind().into(),
+                caller: self.call_site_caller().kind().into(),
+            }
+        } else if self.is_file_line_col_range() {
+            LocationKind::FileLineColRange {
+                filename: self.file_line_col_range_filename(),
+                start_line: self.file_line_col_range_start_line(),
+                start_column: self.file_line_col_range_start_column(),
+                end_line: self.file_line_col_range_end_line(),
+                end_column: self.file_line_col_range_end_column(),
+            }
+        } else if self.is_fused() {
+            LocationKind::Fused {
+                locations: (0..self.fused_num_locations())
+                    .map(|index| self.fused_location(index).kind())
+                    .collect(),
+                metadata: unsafe {
+                    Attribute::from_option_raw(mlirLocationFusedGetMetadata(self.raw))
+                },
+            }
+        } else if self.is_name() {
+            LocationKind::Name {
+                name: self.name_value(),
+                child: self.name_child_location().kind().into(),
+            }
+        } else if *self == Self::unknown(unsafe { self.context().to_ref() }) {
+            LocationKind::Unknown
+        } else {
+            LocationKind::Opaque
+        }
+    }
+
+    /// Renders a source code excerpt annotated with carets at a location.
+    ///
+    /// `sources` is a map from filenames to their contents. It returns `None`
+    /// if a location does not point to any line in the sources.
+    pub fn render_snippet(&self, sources: &HashMap<String, String>) -> Option<String> {
+        let LocationKind::FileLineColRange {
+            filename,
+            start_line,
+            start_column,
+            end_line,
+            end_column,
+        } = self.kind().file_line_col_range()?.clone()
+        else {
+            return None;
+        };
+        let filename = filename.as_string_ref().as_str().ok()?.to_owned();
+        let line = sources
+            .get(&filename)?
+            .lines()
+            .nth(start_line.checked_sub(1)?)?;
+        let width = start_line.to_string().len();
+        let padding = line
+            .chars()
+            .take(start_column.saturating_sub(1))
+            .map(|character| if character == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        let carets = "^".repeat(if end_line == start_line && end_column > start_column {
+            end_column - start_column
+        } else {
+            1
+        });
+
+        Some(format!(
+            "{:width$}--> {filename}:{start_line}:{start_column}\n\
+             {:width$} |\n\
+             {start_line} | {line}\n\
+             {:width$} | {padding}{carets}\n",
+            "", "", ""
+        ))
+    }
+
     /// Returns the callee of a call site location.
     pub fn call_site_callee(&self) -> Self {
         unsafe { Self::from_raw(mlirLocationCallSiteGetCallee(self.raw)) }
@@ -200,6 +284,14 @@ impl<'c> Location<'c> {
         unsafe { Self::from_raw(mlirLocationFromAttribute(attribute.to_raw())) }
     }
 
+    /// Emits an error at a location.
+    ///
+    /// The C API can emit only errors. So, no warnings or remarks can be
+    /// emitted from Rust.
+    pub fn emit_error(self, message: impl Display) -> InFlightDiagnostic<'c> {
+        InFlightDiagnostic::new(self, message)
+    }
+
     /// Creates a location from a raw object.
     ///
     /// # Safety
@@ -243,6 +335,7 @@ impl Display for Location<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::diagnostic::DiagnosticCollector;
     use pretty_assertions::{assert_eq, assert_ne};
 
     #[test]
@@ -497,4 +590,88 @@ mod tests {
         let attr = loc.to_attribute();
         assert_eq!(Location::from_attribute(attr), loc);
     }
+
+    #[test]
+    fn emit_error() {
+        let context = Context::new();
+        let location = Location::new(&context, "foo", 1, 2);
+        let collector = DiagnosticCollector::new(&context);
+
+        location.emit_error("bar").emit();
+
+        let diagnostics = collector.take();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message(), "bar");
+        assert_eq!(diagnostics[0].location(), location);
+    }
+
+    #[test]
+    fn kind() {
+        let context = Context::new();
+        let file = Location::file_line_col_range(&context, "foo", 1, 2, 3, 4);
+        let file_kind = LocationKind::FileLineColRange {
+            filename: Identifier::new(&context, "foo"),
+            start_line: 1,
+            start_column: 2,
+            end_line: 3,
+            end_column: 4,
+        };
+
+        assert_eq!(file.kind(), file_kind);
+        assert_eq!(Location::unknown(&context).kind(), LocationKind::Unknown);
+        assert_eq!(
+            Location::name(&context, "bar", file).kind(),
+            LocationKind::Name {
+                name: Identifier::new(&context, "bar"),
+                child: file_kind.clone().into(),
+            }
+        );
+        assert_eq!(
+            Location::call_site(file, Location::unknown(&context)).kind(),
+            LocationKind::CallSite {
+                callee: file_kind.clone().into(),
+                caller: LocationKind::Unknown.into(),
+            }
+        );
+
+        let LocationKind::Fused { locations, .. } = Location::fused(
+            &context,
+            &[file, Location::new(&context, "baz", 5, 6)],
+            Attribute::unit(&context),
+        )
+        .kind() else {
+            panic!("fused location expected");
+        };
+
+        assert_eq!(locations[0], file_kind);
+        assert_eq!(locations.len(), 2);
+    }
+
+    #[test]
+    fn render_snippet() {
+        let context = Context::new();
+        let sources = HashMap::from([(
+            "foo.x".to_owned(),
+            "let x = 1;\nlet y = x + 2;\n".to_owned(),
+        )]);
+
+        assert_eq!(
+            Location::file_line_col_range(&context, "foo.x", 2, 9, 2, 14)
+                .render_snippet(&sources)
+                .as_deref(),
+            Some(" --> foo.x:2:9\n  |\n2 | let y = x + 2;\n  |         ^^^^^\n")
+        );
+        assert_eq!(
+            Location::name(&context, "bar", Location::new(&context, "foo.x", 1, 5))
+                .render_snippet(&sources)
+                .as_deref(),
+            Some(" --> foo.x:1:5\n  |\n1 | let x = 1;\n  |     ^\n")
+        );
+        assert_eq!(
+            Location::new(&context, "bar.x", 1, 1).render_snippet(&sources),
+            None
+        );
+        assert_eq!(Location::unknown(&context).render_snippet(&sources), None);
+    }
 }
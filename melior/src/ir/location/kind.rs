@@ -0,0 +1,52 @@
+use crate::ir::{Attribute, Identifier};
+
+/// A kind of a location with its components.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LocationKind<'c> {
+    /// A call site location.
+    CallSite {
+        callee: Box<LocationKind<'c>>,
+        caller: Box<LocationKind<'c>>,
+    },
+    /// A file/line/column range location.
+    FileLineColRange {
+        filename: Identifier<'c>,
+        start_line: usize,
+        start_column: usize,
+        end_line: usize,
+        end_column: usize,
+    },
+    /// A fused location.
+    Fused {
+        locations: Vec<LocationKind<'c>>,
+        metadata: Option<Attribute<'c>>,
+    },
+    /// A name location.
+    Name {
+        name: Identifier<'c>,
+        child: Box<LocationKind<'c>>,
+    },
+    /// An opaque location or a location of any other kind.
+    Opaque,
+    /// An unknown location.
+    Unknown,
+}
+
+impl<'c> LocationKind<'c> {
+    /// Returns the first file/line/column range location found in a location
+    /// tree.
+    ///
+    /// It looks up callees of call site locations, children of name
+    /// locations, and locations of fused locations in order.
+    pub fn file_line_col_range(&self) -> Option<&Self> {
+        match self {
+            Self::CallSite { callee, .. } => callee.file_line_col_range(),
+            Self::FileLineColRange { .. } => Some(self),
+            Self::Fused { locations, .. } => locations
+                .iter()
+                .find_map(|location| location.file_line_col_range()),
+            Self::Name { child, .. } => child.file_line_col_range(),
+            Self::Opaque | Self::Unknown => None,
+        }
+    }
+}
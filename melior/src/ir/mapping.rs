@@ -0,0 +1,364 @@
+use crate::{
+    context::Context,
+    ir::{
+        Block, BlockLike, BlockRef, Operation, OperationRef, RegionLike, Value, ValueLike,
+        operation::{OperationLike, WalkOrder, WalkResult},
+    },
+};
+use mlir_sys::{
+    MlirBlock, MlirOperation, MlirRegion, MlirValue, mlirBlockGetArgument,
+    mlirBlockGetFirstOperation, mlirBlockGetNextInRegion, mlirBlockGetNumArguments,
+    mlirOperationClone, mlirOperationGetFirstRegion, mlirOperationGetNextInBlock,
+    mlirOperationGetNumOperands, mlirOperationGetNumResults, mlirOperationGetNumSuccessors,
+    mlirOperationGetOperand, mlirOperationGetResult, mlirOperationGetSuccessor,
+    mlirOperationSetOperand, mlirOperationSetSuccessor, mlirRegionGetFirstBlock,
+    mlirRegionGetNextInOperation,
+};
+use std::{collections::HashMap, ffi::c_void, marker::PhantomData};
+
+/// A mapping of values, blocks, and operations used to clone IR.
+///
+/// Cloning functions, such as [`OperationLike::clone_with_mapping`], replace
+/// uses of mapped values and blocks in clones and record the correspondence
+/// of original and cloned IR objects in a mapping.
+#[derive(Debug, Default)]
+pub struct IrMapping<'c, 'a> {
+    values: HashMap<*const c_void, MlirValue>,
+    blocks: HashMap<*const c_void, MlirBlock>,
+    operations: HashMap<*const c_void, MlirOperation>,
+    _context: PhantomData<&'c Context>,
+    _parent: PhantomData<&'a ()>,
+}
+
+impl<'c, 'a> IrMapping<'c, 'a> {
+    /// Creates a mapping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps a value to another.
+    pub fn map_value(&mut self, from: impl ValueLike<'c>, to: Value<'c, 'a>) {
+        self.values.insert(from.to_raw().ptr, to.to_raw());
+    }
+
+    /// Returns a value mapped from a value.
+    pub fn lookup_value(&self, from: impl ValueLike<'c>) -> Option<Value<'c, 'a>> {
+        self.values
+            .get(&from.to_raw().ptr)
+            .map(|&value| unsafe { Value::from_raw(value) })
+    }
+
+    /// Returns a value mapped from a value, or the value itself if it is not
+    /// mapped.
+    pub fn lookup_value_or_default(&self, from: Value<'c, 'a>) -> Value<'c, 'a> {
+        self.lookup_value(from).unwrap_or(from)
+    }
+
+    /// Returns `true` if a value is mapped.
+    pub fn contains_value(&self, from: impl ValueLike<'c>) -> bool {
+        self.values.contains_key(&from.to_raw().ptr)
+    }
+
+    /// Removes a mapping of a value.
+    pub fn erase_value(&mut self, from: impl ValueLike<'c>) {
+        self.values.remove(&from.to_raw().ptr);
+    }
+
+    /// Maps a block to another.
+    pub fn map_block(&mut self, from: BlockRef<'c, '_>, to: BlockRef<'c, 'a>) {
+        self.blocks
+            .insert(from.to_raw().ptr as *const _, to.to_raw());
+    }
+
+    /// Returns a block mapped from a block.
+    pub fn lookup_block(&self, from: BlockRef<'c, '_>) -> Option<BlockRef<'c, 'a>> {
+        self.blocks
+            .get(&(from.to_raw().ptr as *const _))
+            .map(|&block| unsafe { BlockRef::from_raw(block) })
+    }
+
+    /// Returns `true` if a block is mapped.
+    pub fn contains_block(&self, from: BlockRef<'c, '_>) -> bool {
+        self.blocks.contains_key(&(from.to_raw().ptr as *const _))
+    }
+
+    /// Removes a mapping of a block.
+    pub fn erase_block(&mut self, from: BlockRef<'c, '_>) {
+        self.blocks.remove(&(from.to_raw().ptr as *const _));
+    }
+
+    /// Maps an operation to another.
+    pub fn map_operation(&mut self, from: OperationRef<'c, '_>, to: OperationRef<'c, 'a>) {
+        self.operations
+            .insert(from.to_raw().ptr as *const _, to.to_raw());
+    }
+
+    /// Returns an operation mapped from an operation.
+    pub fn lookup_operation(&self, from: OperationRef<'c, '_>) -> Option<OperationRef<'c, 'a>> {
+        self.operations
+            .get(&(from.to_raw().ptr as *const _))
+            .map(|&operation| unsafe { OperationRef::from_raw(operation) })
+    }
+
+    /// Returns `true` if an operation is mapped.
+    pub fn contains_operation(&self, from: OperationRef<'c, '_>) -> bool {
+        self.operations
+            .contains_key(&(from.to_raw().ptr as *const _))
+    }
+
+    /// Removes a mapping of an operation.
+    pub fn erase_operation(&mut self, from: OperationRef<'c, '_>) {
+        self.operations.remove(&(from.to_raw().ptr as *const _));
+    }
+
+    /// Clears all mappings.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.blocks.clear();
+        self.operations.clear();
+    }
+
+    /// Clones an operation with its operands and successors remapped.
+    pub(crate) fn clone_operation(&mut self, operation: MlirOperation) -> Operation<'c> {
+        let clone = self.clone_operation_without_remapping(operation);
+
+        self.remap_operation(clone);
+
+        unsafe { Operation::from_raw(clone) }
+    }
+
+    /// Clones blocks at the end of a region.
+    ///
+    /// Arguments of the original blocks that are already mapped are not
+    /// added to the cloned blocks.
+    pub(crate) fn clone_blocks(
+        &mut self,
+        blocks: &[MlirBlock],
+        region: &impl RegionLike<'c, 'a>,
+    ) -> Vec<BlockRef<'c, 'a>> {
+        let clones = blocks
+            .iter()
+            .map(|&block| {
+                let clone = region.append_block(Block::new(&[]));
+
+                self.blocks.insert(block.ptr as *const _, clone.to_raw());
+
+                for index in 0..unsafe { mlirBlockGetNumArguments(block) } {
+                    let argument = unsafe { Value::from_raw(mlirBlockGetArgument(block, index)) };
+
+                    if !self.contains_value(argument) {
+                        self.values.insert(
+                            argument.to_raw().ptr,
+                            clone
+                                .add_argument(argument.r#type(), argument.location())
+                                .to_raw(),
+                        );
+                    }
+                }
+
+                clone
+            })
+            .collect::<Vec<_>>();
+
+        // Operands and successors are remapped after all operations are cloned
+        // as they might refer to values or blocks cloned later.
+        for (&block, clone) in blocks.iter().zip(&clones) {
+            let mut operation = unsafe { mlirBlockGetFirstOperation(block) };
+
+            while !operation.ptr.is_null() {
+                clone.append_operation(unsafe {
+                    Operation::from_raw(self.clone_operation_without_remapping(operation))
+                });
+                operation = unsafe { mlirOperationGetNextInBlock(operation) };
+            }
+        }
+
+        for clone in &clones {
+            let mut operation = unsafe { mlirBlockGetFirstOperation(clone.to_raw()) };
+
+            while !operation.ptr.is_null() {
+                self.remap_operation(operation);
+                operation = unsafe { mlirOperationGetNextInBlock(operation) };
+            }
+        }
+
+        clones
+    }
+
+    fn clone_operation_without_remapping(&mut self, operation: MlirOperation) -> MlirOperation {
+        let clone = unsafe { mlirOperationClone(operation) };
+
+        self.map_cloned_operation(operation, clone);
+
+        clone
+    }
+
+    fn map_cloned_operation(&mut self, operation: MlirOperation, clone: MlirOperation) {
+        self.operations.insert(operation.ptr as *const _, clone);
+
+        for index in 0..unsafe { mlirOperationGetNumResults(operation) } {
+            self.values.insert(
+                unsafe { mlirOperationGetResult(operation, index) }.ptr,
+                unsafe { mlirOperationGetResult(clone, index) },
+            );
+        }
+
+        let (mut region, mut cloned_region) = unsafe {
+            (
+                mlirOperationGetFirstRegion(operation),
+                mlirOperationGetFirstRegion(clone),
+            )
+        };
+
+        while !region.ptr.is_null() {
+            self.map_cloned_region(region, cloned_region);
+
+            (region, cloned_region) = unsafe {
+                (
+                    mlirRegionGetNextInOperation(region),
+                    mlirRegionGetNextInOperation(cloned_region),
+                )
+            };
+        }
+    }
+
+    fn map_cloned_region(&mut self, region: MlirRegion, clone: MlirRegion) {
+        let (mut block, mut cloned_block) = unsafe {
+            (
+                mlirRegionGetFirstBlock(region),
+                mlirRegionGetFirstBlock(clone),
+            )
+        };
+
+        while !block.ptr.is_null() {
+            self.blocks.insert(block.ptr as *const _, cloned_block);
+
+            for index in 0..unsafe { mlirBlockGetNumArguments(block) } {
+                self.values
+                    .insert(unsafe { mlirBlockGetArgument(block, index) }.ptr, unsafe {
+                        mlirBlockGetArgument(cloned_block, index)
+                    });
+            }
+
+            let (mut operation, mut cloned_operation) = unsafe {
+                (
+                    mlirBlockGetFirstOperation(block),
+                    mlirBlockGetFirstOperation(cloned_block),
+                )
+            };
+
+            while !operation.ptr.is_null() {
+                self.map_cloned_operation(operation, cloned_operation);
+
+                (operation, cloned_operation) = unsafe {
+                    (
+                        mlirOperationGetNextInBlock(operation),
+                        mlirOperationGetNextInBlock(cloned_operation),
+                    )
+                };
+            }
+
+            (block, cloned_block) = unsafe {
+                (
+                    mlirBlockGetNextInRegion(block),
+                    mlirBlockGetNextInRegion(cloned_block),
+                )
+            };
+        }
+    }
+
+    fn remap_operation(&self, operation: MlirOperation) {
+        unsafe { OperationRef::from_raw(operation) }.walk(WalkOrder::PreOrder, |operation| {
+            let operation = operation.to_raw();
+
+            for index in 0..unsafe { mlirOperationGetNumOperands(operation) } {
+                let operand = unsafe { mlirOperationGetOperand(operation, index) };
+
+                if let Some(&value) = self.values.get(&operand.ptr) {
+                    unsafe { mlirOperationSetOperand(operation, index, value) }
+                }
+            }
+
+            for index in 0..unsafe { mlirOperationGetNumSuccessors(operation) } {
+                let successor = unsafe { mlirOperationGetSuccessor(operation, index) };
+
+                if let Some(&block) = self.blocks.get(&(successor.ptr as *const _)) {
+                    unsafe { mlirOperationSetSuccessor(operation, index, block) }
+                }
+            }
+
+            WalkResult::Advance
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{Location, Region, Type, operation::OperationBuilder},
+        test::create_test_context,
+    };
+
+    #[test]
+    fn map_value() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let from = Value::from(block.argument(0).unwrap());
+        let to = Value::from(block.argument(1).unwrap());
+        let mut mapping = IrMapping::new();
+
+        assert!(!mapping.contains_value(from));
+        assert_eq!(mapping.lookup_value_or_default(from), from);
+
+        mapping.map_value(from, to);
+
+        assert!(mapping.contains_value(from));
+        assert_eq!(mapping.lookup_value(from), Some(to));
+        assert_eq!(mapping.lookup_value_or_default(from), to);
+
+        mapping.erase_value(from);
+
+        assert_eq!(mapping.lookup_value(from), None);
+    }
+
+    #[test]
+    fn map_block() {
+        let region = Region::new();
+        let from = region.append_block(Block::new(&[]));
+        let to = region.append_block(Block::new(&[]));
+        let mut mapping = IrMapping::new();
+
+        mapping.map_block(from, to);
+
+        assert!(mapping.contains_block(from));
+        assert_eq!(mapping.lookup_block(from), Some(to));
+        assert_eq!(mapping.lookup_block(to), None);
+
+        mapping.clear();
+
+        assert!(!mapping.contains_block(from));
+    }
+
+    #[test]
+    fn map_operation() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+        let from = block.append_operation(OperationBuilder::new("foo", location).build().unwrap());
+        let to = block.append_operation(OperationBuilder::new("bar", location).build().unwrap());
+        let mut mapping = IrMapping::new();
+
+        mapping.map_operation(from, to);
+
+        assert!(mapping.contains_operation(from));
+        assert_eq!(mapping.lookup_operation(from), Some(to));
+
+        mapping.erase_operation(from);
+
+        assert!(!mapping.contains_operation(from));
+    }
+}
@@ -1,13 +1,14 @@
 use super::{BlockRef, Location, Operation, OperationRef, operation::OperationRefMut};
 use crate::{
     context::{Context, ContextRef},
+    diagnostic::{OwnedDiagnostic, collect_diagnostics},
     string_ref::StringRef,
 };
 use mlir_sys::{
     MlirModule, mlirModuleCreateEmpty, mlirModuleCreateParse, mlirModuleDestroy,
     mlirModuleFromOperation, mlirModuleGetBody, mlirModuleGetContext, mlirModuleGetOperation,
 };
-use std::{ffi::CString, marker::PhantomData};
+use std::{ffi::CString, marker::PhantomData, mem::forget};
 
 /// A module.
 #[derive(Debug)]
@@ -32,6 +33,17 @@ impl<'c> Module<'c> {
         unsafe { Self::from_option_raw(mlirModuleCreateParse(context.to_raw(), source.to_raw())) }
     }
 
+    /// Parses a module returning diagnostics on failure.
+    pub fn parse_with_diagnostics(
+        context: &'c Context,
+        source: &str,
+    ) -> Result<Self, Vec<OwnedDiagnostic<'c>>> {
+        match collect_diagnostics(context, || Self::parse(context, source)) {
+            (Some(module), _) => Ok(module),
+            (None, diagnostics) => Err(diagnostics),
+        }
+    }
+
     /// Converts a module into an operation.
     pub fn as_operation(&self) -> OperationRef<'c, '_> {
         unsafe { OperationRef::from_raw(mlirModuleGetOperation(self.raw)) }
@@ -86,6 +98,15 @@ impl<'c> Module<'c> {
     pub const fn to_raw(&self) -> MlirModule {
         self.raw
     }
+
+    /// Converts a module into a raw object, transferring ownership.
+    pub const fn into_raw(self) -> MlirModule {
+        let raw = self.raw;
+
+        forget(self);
+
+        raw
+    }
 }
 
 impl Drop for Module<'_> {
@@ -98,6 +119,7 @@ impl Drop for Module<'_> {
 mod tests {
     use super::*;
     use crate::{
+        diagnostic::DiagnosticSeverity,
         ir::{
             Block, Region, RegionLike,
             attribute::StringAttribute,
@@ -126,6 +148,18 @@ mod tests {
         assert!(Module::parse(&Context::new(), "module{").is_none());
     }
 
+    #[test]
+    fn parse_with_diagnostics() {
+        let context = Context::new();
+
+        assert!(Module::parse_with_diagnostics(&context, "module{}").is_ok());
+
+        let diagnostics = Module::parse_with_diagnostics(&context, "module{").unwrap_err();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+    }
+
     #[test]
     fn from_operation() {
         let context = create_test_context();
@@ -0,0 +1,317 @@
+use super::{BlockLike, BlockRef, Location, Operation, OperationRef, operation::OperationLike};
+use std::{cell::Cell, ops::Deref};
+
+/// An insertion point of operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InsertionPoint<'c, 'a> {
+    /// Before an operation.
+    Before(OperationRef<'c, 'a>),
+    /// After an operation.
+    After(OperationRef<'c, 'a>),
+    /// At the start of a block.
+    BlockStart(BlockRef<'c, 'a>),
+    /// At the end of a block.
+    BlockEnd(BlockRef<'c, 'a>),
+}
+
+impl<'c, 'a> InsertionPoint<'c, 'a> {
+    /// Returns a block of the insertion point.
+    pub fn block(&self) -> BlockRef<'c, 'a> {
+        match self {
+            Self::Before(operation) | Self::After(operation) => {
+                operation.block().expect("operation in block")
+            }
+            Self::BlockStart(block) | Self::BlockEnd(block) => *block,
+        }
+    }
+}
+
+/// An operation builder.
+///
+/// It inserts operations at an insertion point with a current location.
+/// Operations created consecutively are inserted in the order of creation.
+pub struct OpBuilder<'c, 'a> {
+    insertion_point: Cell<InsertionPoint<'c, 'a>>,
+    location: Cell<Location<'c>>,
+}
+
+impl<'c, 'a> OpBuilder<'c, 'a> {
+    /// Creates an operation builder.
+    pub fn new(insertion_point: InsertionPoint<'c, 'a>, location: Location<'c>) -> Self {
+        Self {
+            insertion_point: insertion_point.into(),
+            location: location.into(),
+        }
+    }
+
+    /// Creates an operation builder at the end of a block.
+    pub fn at_block_end(block: BlockRef<'c, 'a>, location: Location<'c>) -> Self {
+        Self::new(InsertionPoint::BlockEnd(block), location)
+    }
+
+    /// Returns an insertion point.
+    pub fn insertion_point(&self) -> InsertionPoint<'c, 'a> {
+        self.insertion_point.get()
+    }
+
+    /// Sets an insertion point.
+    pub fn set_insertion_point(&self, insertion_point: InsertionPoint<'c, 'a>) {
+        self.insertion_point.set(insertion_point);
+    }
+
+    /// Sets an insertion point before an operation.
+    pub fn set_insertion_point_before(&self, operation: OperationRef<'c, 'a>) {
+        self.set_insertion_point(InsertionPoint::Before(operation));
+    }
+
+    /// Sets an insertion point after an operation.
+    pub fn set_insertion_point_after(&self, operation: OperationRef<'c, 'a>) {
+        self.set_insertion_point(InsertionPoint::After(operation));
+    }
+
+    /// Sets an insertion point to the start of a block.
+    pub fn set_insertion_point_to_start(&self, block: BlockRef<'c, 'a>) {
+        self.set_insertion_point(InsertionPoint::BlockStart(block));
+    }
+
+    /// Sets an insertion point to the end of a block.
+    pub fn set_insertion_point_to_end(&self, block: BlockRef<'c, 'a>) {
+        self.set_insertion_point(InsertionPoint::BlockEnd(block));
+    }
+
+    /// Returns a block of the insertion point.
+    pub fn insertion_block(&self) -> BlockRef<'c, 'a> {
+        self.insertion_point().block()
+    }
+
+    /// Saves an insertion point and restores it when a returned guard is
+    /// dropped.
+    pub fn insertion_guard(&self) -> InsertionGuard<'_, 'c, 'a> {
+        InsertionGuard {
+            builder: self,
+            insertion_point: self.insertion_point(),
+        }
+    }
+
+    /// Returns a current location.
+    pub fn location(&self) -> Location<'c> {
+        self.location.get()
+    }
+
+    /// Sets a current location.
+    pub fn set_location(&self, location: Location<'c>) {
+        self.location.set(location);
+    }
+
+    /// Sets a current location until a returned guard is dropped.
+    pub fn with_location(&self, location: Location<'c>) -> LocationGuard<'_, 'c, 'a> {
+        LocationGuard {
+            builder: self,
+            location: self.location.replace(location),
+        }
+    }
+
+    /// Inserts an operation at the insertion point.
+    pub fn create(&self, operation: Operation<'c>) -> OperationRef<'c, 'a> {
+        match self.insertion_point() {
+            InsertionPoint::Before(other) => self
+                .insertion_block()
+                .insert_operation_before(other, operation),
+            InsertionPoint::After(other) => {
+                let operation = self
+                    .insertion_block()
+                    .insert_operation_after(other, operation);
+                self.set_insertion_point_after(operation);
+                operation
+            }
+            InsertionPoint::BlockStart(block) => {
+                let operation = block.insert_operation(0, operation);
+                self.set_insertion_point_after(operation);
+                operation
+            }
+            InsertionPoint::BlockEnd(block) => block.append_operation(operation),
+        }
+    }
+}
+
+/// An operation builder passing its current location to operation
+/// constructors.
+pub struct ImplicitLocOpBuilder<'c, 'a> {
+    builder: OpBuilder<'c, 'a>,
+}
+
+impl<'c, 'a> ImplicitLocOpBuilder<'c, 'a> {
+    /// Creates an operation builder.
+    pub fn new(insertion_point: InsertionPoint<'c, 'a>, location: Location<'c>) -> Self {
+        OpBuilder::new(insertion_point, location).into()
+    }
+
+    /// Creates an operation builder at the end of a block.
+    pub fn at_block_end(block: BlockRef<'c, 'a>, location: Location<'c>) -> Self {
+        OpBuilder::at_block_end(block, location).into()
+    }
+
+    /// Inserts an operation constructed with a current location at the
+    /// insertion point.
+    pub fn create(
+        &self,
+        build: impl FnOnce(Location<'c>) -> Operation<'c>,
+    ) -> OperationRef<'c, 'a> {
+        self.builder.create(build(self.location()))
+    }
+}
+
+impl<'c, 'a> Deref for ImplicitLocOpBuilder<'c, 'a> {
+    type Target = OpBuilder<'c, 'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.builder
+    }
+}
+
+impl<'c, 'a> From<OpBuilder<'c, 'a>> for ImplicitLocOpBuilder<'c, 'a> {
+    fn from(builder: OpBuilder<'c, 'a>) -> Self {
+        Self { builder }
+    }
+}
+
+/// A guard restoring an insertion point of an operation builder on drop.
+#[must_use]
+pub struct InsertionGuard<'b, 'c, 'a> {
+    builder: &'b OpBuilder<'c, 'a>,
+    insertion_point: InsertionPoint<'c, 'a>,
+}
+
+impl Drop for InsertionGuard<'_, '_, '_> {
+    fn drop(&mut self) {
+        self.builder.set_insertion_point(self.insertion_point);
+    }
+}
+
+/// A guard restoring a location of an operation builder on drop.
+#[must_use]
+pub struct LocationGuard<'b, 'c, 'a> {
+    builder: &'b OpBuilder<'c, 'a>,
+    location: Location<'c>,
+}
+
+impl Drop for LocationGuard<'_, '_, '_> {
+    fn drop(&mut self) {
+        self.builder.set_location(self.location);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialect::arith,
+        ir::{Module, Type, attribute::IntegerAttribute},
+        test::create_test_context,
+    };
+
+    fn names(block: BlockRef) -> Vec<String> {
+        block
+            .operations()
+            .map(|operation| {
+                operation
+                    .name()
+                    .as_string_ref()
+                    .as_str()
+                    .unwrap()
+                    .to_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn create() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+        let builder = OpBuilder::at_block_end(module.body(), location);
+        let r#type = Type::index(&context);
+
+        let constant = builder.create(arith::constant(
+            &context,
+            IntegerAttribute::new(r#type, 1).into(),
+            builder.location(),
+        ));
+        let value = constant.result(0).unwrap().into();
+
+        builder.set_insertion_point_to_start(module.body());
+        builder.create(arith::constant(
+            &context,
+            IntegerAttribute::new(r#type, 2).into(),
+            builder.location(),
+        ));
+        builder.create(arith::constant(
+            &context,
+            IntegerAttribute::new(r#type, 3).into(),
+            builder.location(),
+        ));
+
+        {
+            let _guard = builder.insertion_guard();
+
+            builder.set_insertion_point_after(constant);
+            builder.create(arith::addi(value, value, builder.location()));
+            builder.create(arith::muli(value, value, builder.location()));
+        }
+
+        let last = builder.create(arith::constant(
+            &context,
+            IntegerAttribute::new(r#type, 4).into(),
+            builder.location(),
+        ));
+
+        assert_eq!(
+            names(module.body()),
+            [
+                "arith.constant",
+                "arith.constant",
+                "arith.constant",
+                "arith.constant",
+                "arith.addi",
+                "arith.muli",
+            ]
+        );
+        assert_eq!(module.body().operations().nth(2), Some(last));
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn with_location() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let other_location = Location::new(&context, "foo", 1, 1);
+        let module = Module::new(location);
+        let builder = OpBuilder::at_block_end(module.body(), location);
+
+        {
+            let _guard = builder.with_location(other_location);
+
+            assert_eq!(builder.location(), other_location);
+        }
+
+        assert_eq!(builder.location(), location);
+    }
+
+    #[test]
+    fn create_implicit_location() {
+        let context = create_test_context();
+        let location = Location::new(&context, "foo", 1, 1);
+        let module = Module::new(Location::unknown(&context));
+        let builder = ImplicitLocOpBuilder::at_block_end(module.body(), location);
+
+        let operation = builder.create(|location| {
+            arith::constant(
+                &context,
+                IntegerAttribute::new(Type::index(&context), 1).into(),
+                location,
+            )
+        });
+
+        assert_eq!(operation.location(), location);
+    }
+}
@@ -1,12 +1,14 @@
 //! Operations and operation builders.
 
 mod builder;
+mod operand;
 mod operation_like;
 mod printing_flags;
 mod result;
 
 pub use self::{
     builder::OperationBuilder,
+    operand::OpOperand,
     operation_like::{OperationLike, OperationMutLike, WalkOrder, WalkResult},
     printing_flags::OperationPrintingFlags,
     result::OperationResult,
@@ -14,6 +16,7 @@ pub use self::{
 use crate::{
     Error,
     context::Context,
+    ir::Downcast,
     utility::{collect_bytes_callback, print_callback, print_string_callback},
 };
 use core::{
@@ -62,6 +65,27 @@ impl Operation<'_> {
         }
     }
 
+    /// Returns `true` if an operation is an instance of `T`.
+    pub fn isa<T: Downcast<Self>>(&self) -> bool {
+        T::isa(self)
+    }
+
+    /// Downcasts an operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an operation is not an instance of `T`.
+    pub fn cast<T: Downcast<Self>>(self) -> T {
+        T::cast(self)
+    }
+
+    /// Downcasts an operation if it is an instance of `T`.
+    ///
+    /// It returns the operation back otherwise.
+    pub fn dyn_cast<T: Downcast<Self>>(self) -> Result<T, Self> {
+        T::dyn_cast(self)
+    }
+
     /// Converts an operation into a raw object.
     pub const fn into_raw(self) -> MlirOperation {
         let operation = self.raw;
@@ -152,6 +176,21 @@ impl<'c, 'a> OperationRef<'c, 'a> {
         unsafe { transmute(self) }
     }
 
+    /// Downcasts an operation reference into a reference to a typed operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an operation is not an instance of `T`.
+    pub fn cast<T: Downcast<Self>>(self) -> T {
+        T::cast(self)
+    }
+
+    /// Downcasts an operation reference into a reference to a typed operation
+    /// if it is an instance of `T`.
+    pub fn dyn_cast<T: Downcast<Self>>(self) -> Option<T> {
+        T::dyn_cast(self).ok()
+    }
+
     /// Converts an operation reference into a raw object.
     pub const fn to_raw(self) -> MlirOperation {
         self.raw
@@ -302,9 +341,10 @@ mod tests {
     use super::*;
     use crate::{
         context::Context,
+        diagnostic::{DiagnosticCollector, DiagnosticSeverity},
         ir::{
-            Block, BlockLike, Identifier, Location, Region, RegionLike, Type, Value,
-            attribute::StringAttribute,
+            Block, BlockLike, Identifier, IrMapping, Location, Module, Region, RegionLike, Type,
+            Value, attribute::StringAttribute,
         },
         test::create_test_context,
     };
@@ -611,6 +651,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn verify_with_diagnostics() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let operation = OperationBuilder::new("arith.addi", location)
+            .add_results(&[Type::index(&context)])
+            .build()
+            .unwrap();
+
+        let diagnostics = operation.verify_with_diagnostics().unwrap_err();
+
+        assert!(!diagnostics.is_empty());
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+        assert_eq!(diagnostics[0].location(), location);
+    }
+
+    #[test]
+    fn verify_with_diagnostics_success() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Module::new(Location::unknown(&context))
+                .as_operation()
+                .verify_with_diagnostics(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn emit_op_error() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let operation = OperationBuilder::new("foo.bar", location).build().unwrap();
+        let collector = DiagnosticCollector::new(&context);
+
+        operation.emit_op_error("failed").append(": baz").emit();
+
+        assert_eq!(collector.take()[0].message(), "'foo.bar' op failed: baz");
+    }
+
+    #[test]
+    fn previous_in_block() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+
+        let first =
+            block.append_operation(OperationBuilder::new("first", location).build().unwrap());
+        let second =
+            block.append_operation(OperationBuilder::new("second", location).build().unwrap());
+
+        assert_eq!(first.previous_in_block(), None);
+        assert_eq!(second.previous_in_block(), Some(first));
+        assert_eq!(
+            second.previous_in_block_mut().unwrap().name(),
+            Identifier::new(&context, "first")
+        );
+    }
+
+    #[test]
+    fn previous_in_block_none() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .build()
+            .unwrap();
+
+        assert_eq!(operation.previous_in_block(), None);
+    }
+
     #[test]
     fn new() {
         let context = create_test_context();
@@ -792,6 +904,37 @@ mod tests {
         let _ = operation.clone();
     }
 
+    #[test]
+    fn clone_with_mapping() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let argument = Value::from(block.argument(0).unwrap());
+        let other_argument = Value::from(block.argument(1).unwrap());
+
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[argument])
+                .add_results(&[r#type])
+                .build()
+                .unwrap(),
+        );
+
+        let mut mapping = IrMapping::new();
+        mapping.map_value(argument, other_argument);
+
+        let clone = block.append_operation(operation.clone_with_mapping(&mut mapping));
+
+        assert_eq!(clone.operand(0).unwrap(), other_argument);
+        assert_eq!(mapping.lookup_operation(operation), Some(clone));
+        assert_eq!(
+            mapping.lookup_value(operation.result(0).unwrap()),
+            Some(clone.result(0).unwrap().into())
+        );
+    }
+
     #[test]
     fn display() {
         let context = create_test_context();
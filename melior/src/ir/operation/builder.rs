@@ -2,6 +2,7 @@ use super::Operation;
 use crate::{
     Error,
     context::Context,
+    diagnostic::observe_diagnostics,
     ir::{Attribute, AttributeLike, Block, Identifier, Location, Region, Type, Value},
     string_ref::StringRef,
 };
@@ -128,9 +129,24 @@ impl<'c> OperationBuilder<'c> {
     }
 
     /// Builds an operation.
+    ///
+    /// Only result type inference can fail. On its failure, an error contains
+    /// diagnostics emitted during the build. The diagnostics are still passed
+    /// on to diagnostic handlers attached to the context.
     pub fn build(mut self) -> Result<Operation<'c>, Error> {
-        unsafe { Operation::from_option_raw(mlirOperationCreate(&mut self.raw)) }
-            .ok_or(Error::OperationBuild)
+        if !self.raw.enableResultTypeInference {
+            return unsafe { Operation::from_option_raw(mlirOperationCreate(&mut self.raw)) }
+                .ok_or(Error::OperationBuild(vec![]));
+        }
+
+        let context = unsafe { Location::from_raw(self.raw.location) }.context();
+        let (operation, diagnostics) =
+            observe_diagnostics(unsafe { context.to_ref() }, || unsafe {
+                Operation::from_option_raw(mlirOperationCreate(&mut self.raw))
+            });
+
+        operation
+            .ok_or_else(|| Error::OperationBuild(diagnostics.into_iter().map(Into::into).collect()))
     }
 }
 
@@ -237,4 +253,20 @@ mod tests {
             r#type,
         );
     }
+
+    #[test]
+    fn build_error() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let Err(Error::OperationBuild(diagnostics)) =
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .enable_result_type_inference()
+                .build()
+        else {
+            panic!("operation build error expected");
+        };
+
+        assert!(!diagnostics.is_empty());
+    }
 }
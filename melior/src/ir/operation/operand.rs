@@ -0,0 +1,138 @@
+use crate::ir::{OperationRef, Value};
+use mlir_sys::{
+    MlirOpOperand, mlirOpOperandGetNextUse, mlirOpOperandGetOperandNumber, mlirOpOperandGetOwner,
+    mlirOpOperandGetValue, mlirOpOperandIsNull,
+};
+use std::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+
+/// An operation operand, which is a use of a value by an operation.
+#[derive(Clone, Copy)]
+pub struct OpOperand<'c, 'a> {
+    raw: MlirOpOperand,
+    _value: PhantomData<Value<'c, 'a>>,
+}
+
+impl<'c, 'a> OpOperand<'c, 'a> {
+    /// Returns an owner operation.
+    pub fn owner(&self) -> OperationRef<'c, 'a> {
+        unsafe { OperationRef::from_raw(mlirOpOperandGetOwner(self.raw)) }
+    }
+
+    /// Returns an operand number in the owner operation.
+    pub fn operand_number(&self) -> usize {
+        unsafe { mlirOpOperandGetOperandNumber(self.raw) as usize }
+    }
+
+    /// Returns a used value.
+    pub fn value(&self) -> Value<'c, 'a> {
+        unsafe { Value::from_raw(mlirOpOperandGetValue(self.raw)) }
+    }
+
+    /// Returns a next use of the same value.
+    pub fn next_use(&self) -> Option<Self> {
+        unsafe { Self::from_option_raw(mlirOpOperandGetNextUse(self.raw)) }
+    }
+
+    /// Creates an operation operand from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirOpOperand) -> Self {
+        Self {
+            raw,
+            _value: Default::default(),
+        }
+    }
+
+    /// Creates an optional operation operand from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_option_raw(raw: MlirOpOperand) -> Option<Self> {
+        if unsafe { mlirOpOperandIsNull(raw) } {
+            None
+        } else {
+            Some(unsafe { Self::from_raw(raw) })
+        }
+    }
+
+    /// Converts an operation operand into a raw object.
+    pub const fn to_raw(self) -> MlirOpOperand {
+        self.raw
+    }
+}
+
+impl Debug for OpOperand<'_, '_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("OpOperand")
+            .field("owner", &self.owner().name())
+            .field("operand_number", &self.operand_number())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ir::{
+            Block, BlockLike, Location, Type, Value, ValueLike,
+            operation::{OperationBuilder, OperationLike},
+        },
+        test::create_test_context,
+    };
+
+    #[test]
+    fn owner_and_operand_number() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location)]);
+        let argument: Value = block.argument(0).unwrap().into();
+
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[argument, argument])
+                .build()
+                .unwrap(),
+        );
+
+        let mut numbers = argument
+            .uses()
+            .map(|operand| {
+                assert_eq!(operand.owner(), operation);
+                assert_eq!(operand.value(), argument);
+
+                operand.operand_number()
+            })
+            .collect::<Vec<_>>();
+        numbers.sort();
+
+        assert_eq!(numbers, [0, 1]);
+    }
+
+    #[test]
+    fn next_use() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location)]);
+        let argument: Value = block.argument(0).unwrap().into();
+
+        block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[argument])
+                .build()
+                .unwrap(),
+        );
+
+        assert!(argument.first_use().unwrap().next_use().is_none());
+    }
+}
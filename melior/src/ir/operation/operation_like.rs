@@ -26,9 +26,11 @@ use mlir_sys::{
 
 use crate::{
     Context, ContextRef, Error, StringRef,
+    diagnostic::{InFlightDiagnostic, OwnedDiagnostic, collect_diagnostics},
     ir::{
-        Attribute, AttributeLike, Block, BlockRef, Identifier, Location, RegionRef, Value,
-        bytecode_writer_config::BytecodeWriterConfig, r#type::TypeId, value::ValueLike,
+        Attribute, AttributeLike, Block, BlockLike, BlockRef, Identifier, IrMapping, Location,
+        Operation, RegionRef, Value, bytecode_writer_config::BytecodeWriterConfig, r#type::TypeId,
+        value::ValueLike,
     },
     logical_result::LogicalResult,
 };
@@ -241,9 +243,30 @@ pub trait OperationLike<'c: 'a, 'a>: Display + 'a {
         unsafe { OperationRefMut::from_option_raw(mlirOperationGetNextInBlock(self.to_raw())) }
     }
 
-    /// Returns a reference to the previous operation in the same block.
+    /// Returns a reference to the previous operation in the same block in O(n)
+    /// time.
+    ///
+    /// This function scans a block from its first operation as the C API does
+    /// not expose backward links of operations. Use
+    /// [`BlockLike::operations`](crate::ir::BlockLike::operations) with
+    /// [`Iterator::rev`] to iterate over operations backward.
     fn previous_in_block(&self) -> Option<OperationRef<'c, 'a>> {
-        todo!("mlirOperationGetPrevInBlock is not exposed in the C API")
+        self.block()?
+            .operations()
+            .take_while(|operation| operation.to_raw().ptr != self.to_raw().ptr)
+            .last()
+    }
+
+    /// Returns a mutable reference to the previous operation in the same block
+    /// in O(n) time.
+    ///
+    /// This function scans a block from its first operation as the C API does
+    /// not expose backward links of operations. Use
+    /// [`BlockLike::operations_mut`](crate::ir::BlockLike::operations_mut) with
+    /// [`Iterator::rev`] to iterate over operations backward.
+    fn previous_in_block_mut(&self) -> Option<OperationRefMut<'c, 'a>> {
+        self.previous_in_block()
+            .map(|operation| unsafe { OperationRefMut::from_raw(operation.to_raw()) })
     }
 
     /// Returns a reference to a parent operation.
@@ -256,11 +279,39 @@ pub trait OperationLike<'c: 'a, 'a>: Display + 'a {
         unsafe { OperationRefMut::from_option_raw(mlirOperationGetParentOperation(self.to_raw())) }
     }
 
+    /// Clones an operation with a mapping.
+    ///
+    /// Uses of values and blocks mapped in a mapping are replaced in the clone.
+    /// Results, blocks, block arguments, and operations of the original
+    /// operation are mapped to the cloned ones in the mapping.
+    fn clone_with_mapping(&self, mapping: &mut IrMapping<'c, 'a>) -> Operation<'c> {
+        mapping.clone_operation(self.to_raw())
+    }
+
     /// Verifies an operation.
     fn verify(&self) -> bool {
         unsafe { mlirOperationVerify(self.to_raw()) }
     }
 
+    /// Verifies an operation returning diagnostics on failure.
+    fn verify_with_diagnostics(&self) -> Result<(), Vec<OwnedDiagnostic<'c>>> {
+        let context = self.context();
+
+        match collect_diagnostics(unsafe { context.to_ref() }, || self.verify()) {
+            (true, _) => Ok(()),
+            (false, diagnostics) => Err(diagnostics),
+        }
+    }
+
+    /// Emits an error at the location of an operation with its name
+    /// prefixed.
+    fn emit_op_error(&self, message: impl Display) -> InFlightDiagnostic<'c> {
+        self.location().emit_error(format!(
+            "'{}' op {message}",
+            self.name().as_string_ref().as_str().unwrap_or_default()
+        ))
+    }
+
     /// Dumps an operation.
     fn dump(&self) {
         unsafe { mlirOperationDump(self.to_raw()) }
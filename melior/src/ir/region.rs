@@ -120,6 +120,13 @@ impl Eq for RegionRef<'_, '_> {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        ir::{
+            BlockLike, IrMapping, Location, Type, Value, ValueLike,
+            operation::{OperationBuilder, OperationLike},
+        },
+        test::create_test_context,
+    };
 
     #[test]
     fn new() {
@@ -131,6 +138,16 @@ mod tests {
         assert!(Region::new().first_block().is_none());
     }
 
+    #[test]
+    fn blocks() {
+        let region = Region::new();
+        let first = region.append_block(Block::new(&[]));
+        let second = region.append_block(Block::new(&[]));
+
+        assert_eq!(region.blocks().collect::<Vec<_>>(), [first, second]);
+        assert_eq!(region.blocks().rev().collect::<Vec<_>>(), [second, first]);
+    }
+
     #[test]
     fn append_block() {
         let region = Region::new();
@@ -172,4 +189,53 @@ mod tests {
     fn not_equal() {
         assert_ne!(Region::new(), Region::new());
     }
+
+    #[test]
+    fn clone_into_region() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[(r#type, location)]));
+        let argument = Value::from(block.argument(0).unwrap());
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[argument])
+                .add_results(&[r#type])
+                .build()
+                .unwrap(),
+        );
+        block.append_operation(
+            OperationBuilder::new("bar", location)
+                .add_operands(&[operation.result(0).unwrap().into()])
+                .build()
+                .unwrap(),
+        );
+
+        let other_block = Block::new(&[(r#type, location)]);
+        let other_argument = Value::from(other_block.argument(0).unwrap());
+        let mut mapping = IrMapping::new();
+        mapping.map_value(argument, other_argument);
+
+        let other_region = Region::new();
+        region.clone_into_region(&other_region, &mut mapping);
+
+        let cloned_block = other_region.first_block().unwrap();
+        let cloned_operation = cloned_block.first_operation().unwrap();
+
+        assert_eq!(mapping.lookup_block(block), Some(cloned_block));
+        assert_eq!(cloned_block.argument_count(), 0);
+        assert_eq!(cloned_operation.operand(0).unwrap(), other_argument);
+        assert_eq!(
+            cloned_operation
+                .next_in_block()
+                .unwrap()
+                .operand(0)
+                .unwrap(),
+            cloned_operation.result(0).unwrap().into()
+        );
+        assert!(other_argument.has_one_use());
+    }
 }
@@ -1,6 +1,6 @@
-use crate::ir::{Block, BlockRef};
+use crate::ir::{Block, BlockLike, BlockRef, IrMapping, list_iterator::ListIterator};
 use mlir_sys::{
-    MlirRegion, mlirRegionAppendOwnedBlock, mlirRegionGetFirstBlock,
+    MlirRegion, mlirBlockGetNextInRegion, mlirRegionAppendOwnedBlock, mlirRegionGetFirstBlock,
     mlirRegionInsertOwnedBlockAfter, mlirRegionInsertOwnedBlockBefore,
 };
 
@@ -22,6 +22,16 @@ pub trait RegionLike<'c, 'a>: 'a {
         }
     }
 
+    /// Returns all blocks.
+    fn blocks(&self) -> impl DoubleEndedIterator<Item = BlockRef<'c, 'a>> {
+        ListIterator::new(self.first_block().map(|block| block.to_raw()), |block| {
+            let block = unsafe { mlirBlockGetNextInRegion(block) };
+
+            (!block.ptr.is_null()).then_some(block)
+        })
+        .map(|block| unsafe { BlockRef::from_raw(block) })
+    }
+
     /// Inserts a block after another block.
     fn insert_block_after(&self, one: BlockRef<'c, 'a>, other: Block<'c>) -> BlockRef<'c, 'a> {
         unsafe {
@@ -54,4 +64,19 @@ pub trait RegionLike<'c, 'a>: 'a {
             r#ref
         }
     }
+
+    /// Clones all blocks of a region at the end of another region with a
+    /// mapping.
+    ///
+    /// Block arguments already mapped in a mapping are not added to the cloned
+    /// blocks and their uses are replaced with the mapped values.
+    fn clone_into_region(&self, region: &impl RegionLike<'c, 'a>, mapping: &mut IrMapping<'c, 'a>) {
+        mapping.clone_blocks(
+            &self
+                .blocks()
+                .map(|block| block.to_raw())
+                .collect::<Vec<_>>(),
+            region,
+        );
+    }
 }
@@ -0,0 +1,434 @@
+//! Symbol tables.
+
+mod visibility;
+
+pub use self::visibility::SymbolVisibility;
+use crate::{
+    Error, StringRef,
+    ir::{
+        Attribute, Operation, OperationRef,
+        attribute::{FlatSymbolRefAttribute, StringAttribute},
+        operation::{OperationLike, OperationMutLike},
+    },
+    logical_result::LogicalResult,
+};
+use mlir_sys::{
+    MlirOperation, MlirSymbolTable, mlirSymbolTableCreate, mlirSymbolTableDestroy,
+    mlirSymbolTableErase, mlirSymbolTableGetSymbolAttributeName,
+    mlirSymbolTableGetVisibilityAttributeName, mlirSymbolTableInsert, mlirSymbolTableLookup,
+    mlirSymbolTableReplaceAllSymbolUses, mlirSymbolTableWalkSymbolTables,
+};
+use std::{ffi::c_void, marker::PhantomData};
+
+/// A symbol table of an operation with the `SymbolTable` trait, such as
+/// `builtin.module`.
+pub struct SymbolTable<'c, 'a> {
+    raw: MlirSymbolTable,
+    _operation: PhantomData<OperationRef<'c, 'a>>,
+}
+
+impl<'c, 'a> SymbolTable<'c, 'a> {
+    /// Creates a symbol table of an operation.
+    ///
+    /// Returns `None` if the operation does not have the `SymbolTable` trait.
+    pub fn new(operation: OperationRef<'c, 'a>) -> Option<Self> {
+        unsafe { Self::from_option_raw(mlirSymbolTableCreate(operation.to_raw())) }
+    }
+
+    /// Returns the name of symbol name attributes.
+    pub fn symbol_attribute_name() -> &'static str {
+        unsafe { StringRef::from_raw(mlirSymbolTableGetSymbolAttributeName()) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns the name of symbol visibility attributes.
+    pub fn visibility_attribute_name() -> &'static str {
+        unsafe { StringRef::from_raw(mlirSymbolTableGetVisibilityAttributeName()) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Looks up a symbol with a name.
+    pub fn lookup(&self, name: &str) -> Option<OperationRef<'c, 'a>> {
+        unsafe {
+            OperationRef::from_option_raw(mlirSymbolTableLookup(
+                self.raw,
+                StringRef::new(name).to_raw(),
+            ))
+        }
+    }
+
+    /// Looks up a symbol referenced by a flat symbol reference.
+    pub fn lookup_symbol(&self, symbol: FlatSymbolRefAttribute) -> Option<OperationRef<'c, 'a>> {
+        self.lookup(symbol.value())
+    }
+
+    /// Inserts a symbol operation at the end of the symbol table operation.
+    ///
+    /// If the symbol table already contains a symbol of the same name, the
+    /// inserted symbol is renamed to be unique. Returns a reference to the
+    /// symbol under its final name.
+    pub fn insert(&self, operation: Operation<'c>) -> FlatSymbolRefAttribute<'c> {
+        let context = operation.context();
+        let name = StringAttribute::try_from(unsafe {
+            Attribute::from_raw(mlirSymbolTableInsert(self.raw, operation.into_raw()))
+        })
+        .expect("symbol name attribute");
+
+        FlatSymbolRefAttribute::new(unsafe { context.to_ref() }, name.value())
+    }
+
+    /// Removes a symbol operation from the symbol table and erases it.
+    ///
+    /// Any reference to the erased operation is invalidated.
+    pub fn erase(&self, operation: OperationRef<'c, 'a>) {
+        unsafe { mlirSymbolTableErase(self.raw, operation.to_raw()) }
+    }
+
+    /// Returns the visibility of a symbol operation.
+    ///
+    /// Symbols without a visibility attribute are public.
+    pub fn visibility<'b>(operation: &impl OperationLike<'c, 'b>) -> Result<SymbolVisibility, Error>
+    where
+        'c: 'b,
+    {
+        let Ok(attribute) = operation.attribute(Self::visibility_attribute_name()) else {
+            return Ok(SymbolVisibility::Public);
+        };
+
+        SymbolVisibility::try_from(StringAttribute::try_from(attribute)?.value())
+    }
+
+    /// Sets the visibility of a symbol operation.
+    ///
+    /// Setting a public visibility removes a visibility attribute as symbols
+    /// are public by default.
+    pub fn set_visibility<'b>(
+        operation: &mut impl OperationMutLike<'c, 'b>,
+        visibility: SymbolVisibility,
+    ) where
+        'c: 'b,
+    {
+        if visibility == SymbolVisibility::Public {
+            // A missing attribute already means public.
+            let _ = operation.remove_attribute(Self::visibility_attribute_name());
+
+            return;
+        }
+
+        let context = operation.context();
+
+        operation.set_attribute(
+            Self::visibility_attribute_name(),
+            StringAttribute::new(unsafe { context.to_ref() }, visibility.as_str()).into(),
+        );
+    }
+
+    /// Replaces all uses of a symbol with another in all nested operations of
+    /// an operation.
+    pub fn replace_all_symbol_uses(
+        old_symbol: FlatSymbolRefAttribute,
+        new_symbol: FlatSymbolRefAttribute,
+        from: OperationRef,
+    ) -> Result<(), Error> {
+        let result = LogicalResult::from_raw(unsafe {
+            mlirSymbolTableReplaceAllSymbolUses(
+                StringRef::new(old_symbol.value()).to_raw(),
+                StringRef::new(new_symbol.value()).to_raw(),
+                from.to_raw(),
+            )
+        });
+
+        if result.is_success() {
+            Ok(())
+        } else {
+            Err(Error::ReplaceSymbolUses(old_symbol.value().into()))
+        }
+    }
+
+    /// Walks all symbol table operations nested in an operation including the
+    /// operation itself.
+    ///
+    /// The callback receives each symbol table operation and a flag indicating
+    /// whether all uses of its symbols are visible.
+    pub fn walk_symbol_tables<F>(
+        from: OperationRef<'c, 'a>,
+        all_symbol_uses_visible: bool,
+        mut callback: F,
+    ) where
+        F: FnMut(OperationRef<'c, 'a>, bool),
+    {
+        unsafe extern "C" fn callback_walk<'c, 'a, F: FnMut(OperationRef<'c, 'a>, bool)>(
+            operation: MlirOperation,
+            all_symbol_uses_visible: bool,
+            data: *mut c_void,
+        ) {
+            let callback = unsafe { &mut *(data as *mut F) };
+
+            callback(
+                unsafe { OperationRef::from_raw(operation) },
+                all_symbol_uses_visible,
+            );
+        }
+
+        unsafe {
+            mlirSymbolTableWalkSymbolTables(
+                from.to_raw(),
+                all_symbol_uses_visible,
+                Some(callback_walk::<'c, 'a, F>),
+                &mut callback as *mut _ as *mut _,
+            )
+        }
+    }
+
+    /// Creates a symbol table from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirSymbolTable) -> Self {
+        Self {
+            raw,
+            _operation: Default::default(),
+        }
+    }
+
+    /// Creates an optional symbol table from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_option_raw(raw: MlirSymbolTable) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Self::from_raw(raw) })
+        }
+    }
+
+    /// Converts a symbol table into a raw object.
+    pub const fn to_raw(&self) -> MlirSymbolTable {
+        self.raw
+    }
+}
+
+impl Drop for SymbolTable<'_, '_> {
+    fn drop(&mut self) {
+        unsafe { mlirSymbolTableDestroy(self.raw) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{Block, BlockLike, Location, Module, operation::OperationBuilder},
+        test::create_test_context,
+    };
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+
+        assert!(SymbolTable::new(module.as_operation()).is_some());
+    }
+
+    #[test]
+    fn new_without_trait() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let block = Block::new(&[]);
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .build()
+                .unwrap(),
+        );
+
+        assert!(SymbolTable::new(operation).is_none());
+    }
+
+    #[test]
+    fn attribute_names() {
+        assert_eq!(SymbolTable::symbol_attribute_name(), "sym_name");
+        assert_eq!(SymbolTable::visibility_attribute_name(), "sym_visibility");
+    }
+
+    #[test]
+    fn lookup() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo() {
+                  return
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let table = SymbolTable::new(module.as_operation()).unwrap();
+
+        assert_eq!(
+            table.lookup("foo").unwrap().name().as_string_ref().as_str(),
+            Ok("func.func")
+        );
+        assert!(table.lookup("bar").is_none());
+        assert!(
+            table
+                .lookup_symbol(FlatSymbolRefAttribute::new(&context, "foo"))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn insert_with_renaming() {
+        let context = create_test_context();
+        let module = Module::parse(&context, "func.func private @foo()").unwrap();
+        let table = SymbolTable::new(module.as_operation()).unwrap();
+
+        let symbol = table.insert(Operation::clone(&module.body().first_operation().unwrap()));
+
+        assert_ne!(symbol.value(), "foo");
+        assert!(table.lookup(symbol.value()).is_some());
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn erase() {
+        let context = create_test_context();
+        let module = Module::parse(&context, "func.func private @foo()").unwrap();
+        let table = SymbolTable::new(module.as_operation()).unwrap();
+
+        table.erase(table.lookup("foo").unwrap());
+
+        assert!(table.lookup("foo").is_none());
+        assert!(module.body().first_operation().is_none());
+    }
+
+    #[test]
+    fn visibility() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func private @foo()
+                func.func @bar() {
+                  return
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let table = SymbolTable::new(module.as_operation()).unwrap();
+
+        assert_eq!(
+            SymbolTable::visibility(&table.lookup("foo").unwrap()),
+            Ok(SymbolVisibility::Private)
+        );
+        assert_eq!(
+            SymbolTable::visibility(&table.lookup("bar").unwrap()),
+            Ok(SymbolVisibility::Public)
+        );
+    }
+
+    #[test]
+    fn set_visibility() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo() {
+                  return
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let table = SymbolTable::new(module.as_operation()).unwrap();
+        let mut operation = module.body().first_operation_mut().unwrap();
+
+        SymbolTable::set_visibility(&mut operation, SymbolVisibility::Private);
+
+        assert_eq!(
+            SymbolTable::visibility(&table.lookup("foo").unwrap()),
+            Ok(SymbolVisibility::Private)
+        );
+
+        SymbolTable::set_visibility(&mut operation, SymbolVisibility::Public);
+
+        assert_eq!(
+            SymbolTable::visibility(&table.lookup("foo").unwrap()),
+            Ok(SymbolVisibility::Public)
+        );
+        assert!(
+            operation
+                .attribute(SymbolTable::visibility_attribute_name())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn replace_all_symbol_uses() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func private @foo()
+                func.func private @bar()
+                func.func @baz() {
+                  call @foo() : () -> ()
+                  return
+                }
+                "
+            ),
+        )
+        .unwrap();
+
+        SymbolTable::replace_all_symbol_uses(
+            FlatSymbolRefAttribute::new(&context, "foo"),
+            FlatSymbolRefAttribute::new(&context, "bar"),
+            module.as_operation(),
+        )
+        .unwrap();
+
+        assert!(module.as_operation().to_string().contains("call @bar()"));
+    }
+
+    #[test]
+    fn walk_symbol_tables() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                module @inner {
+                }
+                func.func private @foo()
+                "
+            ),
+        )
+        .unwrap();
+        let mut names = vec![];
+
+        SymbolTable::walk_symbol_tables(module.as_operation(), true, |operation, _| {
+            names.push(
+                operation
+                    .name()
+                    .as_string_ref()
+                    .as_str()
+                    .unwrap()
+                    .to_owned(),
+            );
+        });
+
+        assert_eq!(names, ["builtin.module", "builtin.module"]);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::Error;
+
+/// Symbol visibility.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SymbolVisibility {
+    /// A symbol visible from outside of its symbol table.
+    #[default]
+    Public,
+    /// A symbol visible only from within its symbol table.
+    Private,
+    /// A symbol visible from its symbol table and the ones nested inside
+    /// its parent operation.
+    Nested,
+}
+
+impl SymbolVisibility {
+    /// Returns a string used in `sym_visibility` attributes.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Private => "private",
+            Self::Nested => "nested",
+        }
+    }
+}
+
+impl TryFrom<&str> for SymbolVisibility {
+    type Error = Error;
+
+    fn try_from(visibility: &str) -> Result<Self, Error> {
+        Ok(match visibility {
+            "public" => Self::Public,
+            "private" => Self::Private,
+            "nested" => Self::Nested,
+            _ => return Err(Error::UnknownSymbolVisibility(visibility.into())),
+        })
+    }
+}
@@ -2,27 +2,46 @@
 
 #[macro_use]
 mod r#macro;
+mod complex;
+mod float;
 mod function;
 pub mod id;
 mod integer;
 mod mem_ref;
+mod none;
+mod opaque;
 mod ranked_tensor;
 mod shaped_type_like;
 mod tuple;
 mod type_like;
+mod unranked_mem_ref;
+mod unranked_tensor;
+mod vector;
 
 pub use self::{
+    complex::ComplexType,
+    float::FloatType,
     function::FunctionType,
     id::TypeId,
     integer::IntegerType,
     mem_ref::MemRefType,
+    none::NoneType,
+    opaque::OpaqueType,
     ranked_tensor::RankedTensorType,
     shaped_type_like::{DimSize, ShapedTypeLike},
     tuple::TupleType,
     type_like::TypeLike,
+    unranked_mem_ref::UnrankedMemRefType,
+    unranked_tensor::UnrankedTensorType,
+    vector::VectorType,
+};
+use super::{Downcast, Location};
+use crate::{
+    context::Context,
+    diagnostic::{OwnedDiagnostic, collect_diagnostics},
+    string_ref::StringRef,
+    utility::print_callback,
 };
-use super::Location;
-use crate::{context::Context, string_ref::StringRef, utility::print_callback};
 use mlir_sys::{
     MlirType, mlirBF16TypeGet, mlirF16TypeGet, mlirF32TypeGet, mlirF64TypeGet,
     mlirFloat4E2M1FNTypeGet, mlirFloat6E2M3FNTypeGet, mlirFloat6E3M2FNTypeGet,
@@ -30,8 +49,7 @@ use mlir_sys::{
     mlirFloat8E4M3FNUZTypeGet, mlirFloat8E4M3TypeGet, mlirFloat8E5M2FNUZTypeGet,
     mlirFloat8E5M2TypeGet, mlirFloat8E8M0FNUTypeGet, mlirFloatTypeGetWidth, mlirIndexTypeGet,
     mlirNoneTypeGet, mlirTypeEqual, mlirTypeParseGet, mlirTypePrint, mlirVectorTypeGet,
-    mlirVectorTypeGetChecked, mlirVectorTypeGetScalable, mlirVectorTypeGetScalableChecked,
-    mlirVectorTypeIsDimScalable, mlirVectorTypeIsScalable,
+    mlirVectorTypeGetChecked,
 };
 use std::{
     ffi::c_void,
@@ -58,6 +76,17 @@ impl<'c> Type<'c> {
         }
     }
 
+    /// Parses a type returning diagnostics on failure.
+    pub fn parse_with_diagnostics(
+        context: &'c Context,
+        source: &str,
+    ) -> Result<Self, Vec<OwnedDiagnostic<'c>>> {
+        match collect_diagnostics(context, || Self::parse(context, source)) {
+            (Some(r#type), _) => Ok(r#type),
+            (None, diagnostics) => Err(diagnostics),
+        }
+    }
+
     /// Creates a bfloat16 type.
     pub fn bfloat16(context: &'c Context) -> Self {
         unsafe { Self::from_raw(mlirBF16TypeGet(context.to_raw())) }
@@ -175,44 +204,23 @@ impl<'c> Type<'c> {
         }
     }
 
-    /// Creates a scalable vector type.
-    pub fn vector_scalable(dimensions: &[u64], scalable: &[bool], element_type: Self) -> Self {
-        unsafe {
-            Self::from_raw(mlirVectorTypeGetScalable(
-                dimensions.len() as isize,
-                dimensions.as_ptr() as *const i64,
-                scalable.as_ptr(),
-                element_type.raw,
-            ))
-        }
-    }
-
-    /// Creates a scalable vector type with diagnostics.
-    pub fn vector_scalable_checked(
-        location: Location<'c>,
-        dimensions: &[u64],
-        scalable: &[bool],
-        element_type: Self,
-    ) -> Option<Self> {
-        unsafe {
-            Self::from_option_raw(mlirVectorTypeGetScalableChecked(
-                location.to_raw(),
-                dimensions.len() as isize,
-                dimensions.as_ptr() as *const i64,
-                scalable.as_ptr(),
-                element_type.raw,
-            ))
-        }
+    /// Returns `true` if a type is an instance of `T`.
+    pub fn isa<T: Downcast<Self>>(&self) -> bool {
+        T::isa(self)
     }
 
-    /// Returns `true` if the vector type has at least one scalable dimension.
-    pub fn is_scalable_vector(&self) -> bool {
-        unsafe { mlirVectorTypeIsScalable(self.raw) }
+    /// Downcasts a type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a type is not an instance of `T`.
+    pub fn cast<T: Downcast<Self>>(self) -> T {
+        T::cast(self)
     }
 
-    /// Returns `true` if the given dimension of a vector type is scalable.
-    pub fn is_vector_dim_scalable(&self, dim: usize) -> bool {
-        unsafe { mlirVectorTypeIsDimScalable(self.raw, dim as isize) }
+    /// Downcasts a type if it is an instance of `T`.
+    pub fn dyn_cast<T: Downcast<Self>>(self) -> Option<T> {
+        T::dyn_cast(self).ok()
     }
 
     /// Creates a type from a raw object.
@@ -288,11 +296,18 @@ impl std::hash::Hash for Type<'_> {
 
 from_subtypes!(
     Type,
+    ComplexType,
+    FloatType,
     FunctionType,
     IntegerType,
     MemRefType,
+    NoneType,
+    OpaqueType,
     RankedTensorType,
-    TupleType
+    TupleType,
+    UnrankedMemRefType,
+    UnrankedTensorType,
+    VectorType,
 );
 
 #[cfg(test)]
@@ -307,6 +322,24 @@ mod tests {
         Type::parse(&context, "f32");
     }
 
+    #[test]
+    fn parse_with_diagnostics() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Type::parse_with_diagnostics(&context, "index"),
+            Ok(Type::index(&context))
+        );
+
+        let diagnostics = Type::parse_with_diagnostics(&context, "z").unwrap_err();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].severity(),
+            crate::diagnostic::DiagnosticSeverity::Error
+        );
+    }
+
     #[test]
     fn integer() {
         let context = create_test_context();
@@ -520,59 +553,13 @@ mod tests {
     }
 
     #[test]
-    fn vector_scalable() {
-        let context = create_test_context();
-
-        assert_eq!(
-            Type::vector_scalable(&[4], &[true], Type::float32(&context)),
-            Type::parse(&context, "vector<[4]xf32>").unwrap()
-        );
-    }
-
-    #[test]
-    fn vector_scalable_checked() {
-        let context = create_test_context();
-
-        assert_eq!(
-            Type::vector_scalable_checked(
-                Location::unknown(&context),
-                &[4],
-                &[true],
-                Type::float32(&context),
-            ),
-            Type::parse(&context, "vector<[4]xf32>")
-        );
-    }
-
-    #[test]
-    fn vector_scalable_checked_fail() {
-        let context = create_test_context();
-
-        assert_eq!(
-            Type::vector_scalable_checked(
-                Location::unknown(&context),
-                &[0],
-                &[true],
-                Type::index(&context),
-            ),
-            None
-        );
-    }
-
-    #[test]
-    fn is_scalable_vector() {
-        let context = create_test_context();
-
-        assert!(Type::vector_scalable(&[4], &[true], Type::float32(&context)).is_scalable_vector());
-        assert!(!Type::vector(&[4], Type::float32(&context)).is_scalable_vector());
-    }
-
-    #[test]
-    fn is_vector_dim_scalable() {
+    fn downcast() {
         let context = create_test_context();
+        let r#type = Type::parse(&context, "vector<4xf32>").unwrap();
 
-        let scalable = Type::vector_scalable(&[4, 8], &[true, false], Type::float32(&context));
-        assert!(scalable.is_vector_dim_scalable(0));
-        assert!(!scalable.is_vector_dim_scalable(1));
+        assert!(r#type.isa::<VectorType>());
+        assert!(!r#type.isa::<FloatType>());
+        assert_eq!(r#type.cast::<VectorType>().rank(), 1);
+        assert!(r#type.dyn_cast::<MemRefType>().is_none());
     }
 }
@@ -0,0 +1,49 @@
+use super::TypeLike;
+use crate::{Error, ir::Type};
+use mlir_sys::{MlirType, mlirComplexTypeGet, mlirComplexTypeGetElementType};
+
+/// A complex type.
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct ComplexType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> ComplexType<'c> {
+    /// Creates a complex type.
+    pub fn new(element_type: Type<'c>) -> Self {
+        unsafe { Self::from_raw(mlirComplexTypeGet(element_type.to_raw())) }
+    }
+
+    /// Returns an element type.
+    pub fn element(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirComplexTypeGetElementType(self.r#type.to_raw())) }
+    }
+}
+
+type_traits!(ComplexType, is_complex, "complex");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(ComplexType::new(Type::float32(&context))),
+            Type::parse(&context, "complex<f32>").unwrap()
+        );
+    }
+
+    #[test]
+    fn element() {
+        let context = Context::new();
+
+        assert_eq!(
+            ComplexType::new(Type::float32(&context)).element(),
+            Type::float32(&context)
+        );
+    }
+}
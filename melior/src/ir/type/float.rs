@@ -0,0 +1,154 @@
+use super::TypeLike;
+use crate::{Context, Error, ir::Type};
+use mlir_sys::{
+    MlirType, mlirBF16TypeGet, mlirF16TypeGet, mlirF32TypeGet, mlirF64TypeGet,
+    mlirFloatTypeGetWidth, mlirTF32TypeGet, mlirTypeIsABF16, mlirTypeIsAF16, mlirTypeIsAF32,
+    mlirTypeIsAF64, mlirTypeIsAFloat4E2M1FN, mlirTypeIsAFloat6E2M3FN, mlirTypeIsAFloat6E3M2FN,
+    mlirTypeIsAFloat8E3M4, mlirTypeIsAFloat8E4M3, mlirTypeIsAFloat8E4M3B11FNUZ,
+    mlirTypeIsAFloat8E4M3FN, mlirTypeIsAFloat8E4M3FNUZ, mlirTypeIsAFloat8E5M2,
+    mlirTypeIsAFloat8E5M2FNUZ, mlirTypeIsAFloat8E8M0FNU, mlirTypeIsATF32,
+};
+
+/// A float type.
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct FloatType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> FloatType<'c> {
+    /// Creates a bfloat16 type.
+    pub fn bfloat16(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirBF16TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float16 type.
+    pub fn float16(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirF16TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float32 type.
+    pub fn float32(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirF32TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float64 type.
+    pub fn float64(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirF64TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a tf32 type.
+    pub fn tf32(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirTF32TypeGet(context.to_raw())) }
+    }
+
+    /// Returns a bit width.
+    pub fn width(&self) -> u32 {
+        unsafe { mlirFloatTypeGetWidth(self.r#type.to_raw()) }
+    }
+
+    /// Returns a mantissa width including an implicit leading bit.
+    ///
+    /// It returns `None` for float types unknown to this crate.
+    pub fn mantissa_width(&self) -> Option<u32> {
+        let raw = self.r#type.to_raw();
+
+        unsafe {
+            Some(if mlirTypeIsAF64(raw) {
+                53
+            } else if mlirTypeIsAF32(raw) {
+                24
+            } else if mlirTypeIsAF16(raw) || mlirTypeIsATF32(raw) {
+                11
+            } else if mlirTypeIsABF16(raw) {
+                8
+            } else if mlirTypeIsAFloat8E3M4(raw) {
+                5
+            } else if mlirTypeIsAFloat8E4M3(raw)
+                || mlirTypeIsAFloat8E4M3FN(raw)
+                || mlirTypeIsAFloat8E4M3FNUZ(raw)
+                || mlirTypeIsAFloat8E4M3B11FNUZ(raw)
+                || mlirTypeIsAFloat6E2M3FN(raw)
+            {
+                4
+            } else if mlirTypeIsAFloat8E5M2(raw)
+                || mlirTypeIsAFloat8E5M2FNUZ(raw)
+                || mlirTypeIsAFloat6E3M2FN(raw)
+            {
+                3
+            } else if mlirTypeIsAFloat4E2M1FN(raw) {
+                2
+            } else if mlirTypeIsAFloat8E8M0FNU(raw) {
+                1
+            } else {
+                return None;
+            })
+        }
+    }
+}
+
+type_traits!(FloatType, is_float, "float");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(FloatType::bfloat16(&context)),
+            Type::bfloat16(&context)
+        );
+        assert_eq!(
+            Type::from(FloatType::float16(&context)),
+            Type::float16(&context)
+        );
+        assert_eq!(
+            Type::from(FloatType::float32(&context)),
+            Type::float32(&context)
+        );
+        assert_eq!(
+            Type::from(FloatType::float64(&context)),
+            Type::float64(&context)
+        );
+        assert_eq!(
+            Type::from(FloatType::tf32(&context)),
+            Type::parse(&context, "tf32").unwrap()
+        );
+    }
+
+    #[test]
+    fn width() {
+        let context = Context::new();
+
+        assert_eq!(FloatType::bfloat16(&context).width(), 16);
+        assert_eq!(FloatType::tf32(&context).width(), 19);
+        assert_eq!(FloatType::float64(&context).width(), 64);
+    }
+
+    #[test]
+    fn mantissa_width() {
+        let context = Context::new();
+
+        assert_eq!(FloatType::bfloat16(&context).mantissa_width(), Some(8));
+        assert_eq!(FloatType::float16(&context).mantissa_width(), Some(11));
+        assert_eq!(FloatType::tf32(&context).mantissa_width(), Some(11));
+        assert_eq!(FloatType::float32(&context).mantissa_width(), Some(24));
+        assert_eq!(FloatType::float64(&context).mantissa_width(), Some(53));
+        assert_eq!(
+            FloatType::try_from(Type::float8_e5m2(&context))
+                .unwrap()
+                .mantissa_width(),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(FloatType::try_from(Type::float32(&context)).is_ok());
+        assert!(FloatType::try_from(Type::index(&context)).is_err());
+    }
+}
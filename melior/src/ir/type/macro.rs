@@ -20,6 +20,16 @@ macro_rules! type_traits {
             }
         }
 
+        impl<'c> crate::ir::Downcast<crate::ir::r#type::Type<'c>> for $name<'c> {
+            fn isa(r#type: &crate::ir::r#type::Type<'c>) -> bool {
+                r#type.$is_type()
+            }
+
+            unsafe fn cast_unchecked(r#type: crate::ir::r#type::Type<'c>) -> Self {
+                unsafe { Self::from_raw(r#type.to_raw()) }
+            }
+        }
+
         impl<'c> crate::ir::r#type::TypeLike<'c> for $name<'c> {
             fn to_raw(&self) -> mlir_sys::MlirType {
                 self.r#type.to_raw()
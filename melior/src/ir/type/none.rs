@@ -0,0 +1,30 @@
+use super::TypeLike;
+use crate::{Context, Error, ir::Type};
+use mlir_sys::{MlirType, mlirNoneTypeGet};
+
+/// A none type.
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct NoneType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> NoneType<'c> {
+    /// Creates a none type.
+    pub fn new(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirNoneTypeGet(context.to_raw())) }
+    }
+}
+
+type_traits!(NoneType, is_none, "none");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(Type::from(NoneType::new(&context)), Type::none(&context));
+    }
+}
@@ -0,0 +1,60 @@
+use super::TypeLike;
+use crate::{Context, Error, StringRef, ir::Type};
+use mlir_sys::{
+    MlirType, mlirOpaqueTypeGet, mlirOpaqueTypeGetData, mlirOpaqueTypeGetDialectNamespace,
+};
+
+/// An opaque type.
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct OpaqueType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> OpaqueType<'c> {
+    /// Creates an opaque type.
+    pub fn new(context: &'c Context, dialect_namespace: &str, data: &str) -> Self {
+        unsafe {
+            Self::from_raw(mlirOpaqueTypeGet(
+                context.to_raw(),
+                StringRef::new(dialect_namespace).to_raw(),
+                StringRef::new(data).to_raw(),
+            ))
+        }
+    }
+
+    /// Returns a dialect namespace.
+    pub fn dialect_namespace(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirOpaqueTypeGetDialectNamespace(self.r#type.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns data.
+    pub fn data(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirOpaqueTypeGetData(self.r#type.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+}
+
+type_traits!(OpaqueType, is_opaque, "opaque");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+        context.set_allow_unregistered_dialects(true);
+
+        let r#type = OpaqueType::new(&context, "foo", "bar");
+
+        assert_eq!(r#type.dialect_namespace(), "foo");
+        assert_eq!(r#type.data(), "bar");
+        assert_eq!(
+            Type::from(r#type),
+            Type::parse(&context, "!foo.bar").unwrap()
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use super::TypeLike;
+use crate::{
+    Error,
+    ir::{Attribute, Location, Type, attribute::AttributeLike},
+};
+use mlir_sys::{
+    MlirType, mlirShapedTypeGetElementType, mlirUnrankedMemRefTypeGet,
+    mlirUnrankedMemRefTypeGetChecked, mlirUnrankedMemrefGetMemorySpace,
+};
+
+/// An unranked mem-ref type.
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct UnrankedMemRefType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> UnrankedMemRefType<'c> {
+    /// Creates an unranked mem-ref type.
+    pub fn new(element_type: Type<'c>, memory_space: Option<Attribute<'c>>) -> Self {
+        unsafe {
+            Self::from_raw(mlirUnrankedMemRefTypeGet(
+                element_type.to_raw(),
+                memory_space.unwrap_or_else(|| Attribute::null()).to_raw(),
+            ))
+        }
+    }
+
+    /// Creates an unranked mem-ref type with diagnostics.
+    pub fn checked(
+        location: Location<'c>,
+        element_type: Type<'c>,
+        memory_space: Option<Attribute<'c>>,
+    ) -> Option<Self> {
+        let raw = unsafe {
+            mlirUnrankedMemRefTypeGetChecked(
+                location.to_raw(),
+                element_type.to_raw(),
+                memory_space.unwrap_or_else(|| Attribute::null()).to_raw(),
+            )
+        };
+
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Self::from_raw(raw) })
+        }
+    }
+
+    /// Returns an element type.
+    pub fn element(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirShapedTypeGetElementType(self.r#type.to_raw())) }
+    }
+
+    /// Returns a memory space.
+    pub fn memory_space(&self) -> Option<Attribute<'c>> {
+        unsafe {
+            Attribute::from_option_raw(mlirUnrankedMemrefGetMemorySpace(self.r#type.to_raw()))
+        }
+    }
+}
+
+type_traits!(UnrankedMemRefType, is_unranked_mem_ref, "unranked mem ref");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+        let r#type = UnrankedMemRefType::new(Type::float32(&context), None);
+
+        assert_eq!(
+            Type::from(r#type),
+            Type::parse(&context, "memref<*xf32>").unwrap()
+        );
+        assert_eq!(r#type.element(), Type::float32(&context));
+        assert_eq!(r#type.memory_space(), None);
+    }
+
+    #[test]
+    fn memory_space() {
+        let context = Context::new();
+        let memory_space = Attribute::parse(&context, "1 : i64").unwrap();
+
+        assert_eq!(
+            UnrankedMemRefType::new(Type::float32(&context), Some(memory_space)).memory_space(),
+            Some(memory_space)
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let context = Context::new();
+
+        assert_eq!(
+            UnrankedMemRefType::checked(Location::unknown(&context), Type::float32(&context), None)
+                .map(Type::from),
+            Type::parse(&context, "memref<*xf32>")
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use super::TypeLike;
+use crate::{
+    Error,
+    ir::{Location, Type},
+};
+use mlir_sys::{
+    MlirType, mlirShapedTypeGetElementType, mlirUnrankedTensorTypeGet,
+    mlirUnrankedTensorTypeGetChecked,
+};
+
+/// An unranked tensor type.
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct UnrankedTensorType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> UnrankedTensorType<'c> {
+    /// Creates an unranked tensor type.
+    pub fn new(element_type: Type<'c>) -> Self {
+        unsafe { Self::from_raw(mlirUnrankedTensorTypeGet(element_type.to_raw())) }
+    }
+
+    /// Creates an unranked tensor type with diagnostics.
+    pub fn checked(location: Location<'c>, element_type: Type<'c>) -> Option<Self> {
+        let raw =
+            unsafe { mlirUnrankedTensorTypeGetChecked(location.to_raw(), element_type.to_raw()) };
+
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Self::from_raw(raw) })
+        }
+    }
+
+    /// Returns an element type.
+    pub fn element(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirShapedTypeGetElementType(self.r#type.to_raw())) }
+    }
+}
+
+type_traits!(UnrankedTensorType, is_unranked_tensor, "unranked tensor");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+        let r#type = UnrankedTensorType::new(Type::float32(&context));
+
+        assert_eq!(
+            Type::from(r#type),
+            Type::parse(&context, "tensor<*xf32>").unwrap()
+        );
+        assert_eq!(r#type.element(), Type::float32(&context));
+    }
+
+    #[test]
+    fn checked() {
+        let context = Context::new();
+
+        assert_eq!(
+            UnrankedTensorType::checked(Location::unknown(&context), Type::float32(&context))
+                .map(Type::from),
+            Type::parse(&context, "tensor<*xf32>")
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use super::{TypeLike, shaped_type_like::ShapedTypeLike};
+use crate::{
+    Error,
+    ir::{Location, Type},
+};
+use mlir_sys::{
+    MlirType, mlirVectorTypeGet, mlirVectorTypeGetChecked, mlirVectorTypeGetScalable,
+    mlirVectorTypeGetScalableChecked, mlirVectorTypeIsDimScalable, mlirVectorTypeIsScalable,
+};
+
+/// A vector type.
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct VectorType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> VectorType<'c> {
+    /// Creates a vector type.
+    pub fn new(dimensions: &[u64], element_type: Type<'c>) -> Self {
+        unsafe {
+            Self::from_raw(mlirVectorTypeGet(
+                dimensions.len() as isize,
+                dimensions.as_ptr() as *const i64,
+                element_type.to_raw(),
+            ))
+        }
+    }
+
+    /// Creates a vector type with diagnostics.
+    pub fn checked(
+        location: Location<'c>,
+        dimensions: &[u64],
+        element_type: Type<'c>,
+    ) -> Option<Self> {
+        unsafe {
+            Self::from_option_raw(mlirVectorTypeGetChecked(
+                location.to_raw(),
+                dimensions.len() as isize,
+                dimensions.as_ptr() as *const i64,
+                element_type.to_raw(),
+            ))
+        }
+    }
+
+    /// Creates a scalable vector type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numbers of dimensions and scalable flags differ.
+    pub fn scalable(dimensions: &[u64], scalable: &[bool], element_type: Type<'c>) -> Self {
+        assert_eq!(dimensions.len(), scalable.len());
+
+        unsafe {
+            Self::from_raw(mlirVectorTypeGetScalable(
+                dimensions.len() as isize,
+                dimensions.as_ptr() as *const i64,
+                scalable.as_ptr(),
+                element_type.to_raw(),
+            ))
+        }
+    }
+
+    /// Creates a scalable vector type with diagnostics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numbers of dimensions and scalable flags differ.
+    pub fn scalable_checked(
+        location: Location<'c>,
+        dimensions: &[u64],
+        scalable: &[bool],
+        element_type: Type<'c>,
+    ) -> Option<Self> {
+        assert_eq!(dimensions.len(), scalable.len());
+
+        unsafe {
+            Self::from_option_raw(mlirVectorTypeGetScalableChecked(
+                location.to_raw(),
+                dimensions.len() as isize,
+                dimensions.as_ptr() as *const i64,
+                scalable.as_ptr(),
+                element_type.to_raw(),
+            ))
+        }
+    }
+
+    /// Returns `true` if the vector type has at least one scalable dimension.
+    pub fn is_scalable(&self) -> bool {
+        unsafe { mlirVectorTypeIsScalable(self.r#type.to_raw()) }
+    }
+
+    /// Returns `true` if a dimension is scalable.
+    pub fn is_dim_scalable(&self, index: usize) -> Result<bool, Error> {
+        if index < self.rank() {
+            Ok(unsafe { mlirVectorTypeIsDimScalable(self.r#type.to_raw(), index as isize) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "vector dimension",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns scalable flags of dimensions.
+    pub fn scalable_dims(&self) -> Vec<bool> {
+        (0..self.rank())
+            .map(|index| unsafe {
+                mlirVectorTypeIsDimScalable(self.r#type.to_raw(), index as isize)
+            })
+            .collect()
+    }
+
+    unsafe fn from_option_raw(raw: MlirType) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Self::from_raw(raw) })
+        }
+    }
+}
+
+impl<'c> ShapedTypeLike<'c> for VectorType<'c> {}
+
+type_traits!(VectorType, is_vector, "vector");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, ir::r#type::DimSize};
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(VectorType::new(&[42], Type::float64(&context))),
+            Type::parse(&context, "vector<42xf64>").unwrap()
+        );
+    }
+
+    #[test]
+    fn checked_fail() {
+        let context = Context::new();
+
+        assert!(
+            VectorType::checked(Location::unknown(&context), &[0], Type::index(&context)).is_none()
+        );
+    }
+
+    #[test]
+    fn scalable() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(VectorType::scalable(&[4], &[true], Type::float32(&context))),
+            Type::parse(&context, "vector<[4]xf32>").unwrap()
+        );
+    }
+
+    #[test]
+    fn scalable_checked() {
+        let context = Context::new();
+
+        assert_eq!(
+            VectorType::scalable_checked(
+                Location::unknown(&context),
+                &[4],
+                &[true],
+                Type::float32(&context),
+            )
+            .map(Type::from),
+            Type::parse(&context, "vector<[4]xf32>")
+        );
+    }
+
+    #[test]
+    fn scalable_checked_fail() {
+        let context = Context::new();
+
+        assert!(
+            VectorType::scalable_checked(
+                Location::unknown(&context),
+                &[0],
+                &[true],
+                Type::index(&context),
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn is_scalable() {
+        let context = Context::new();
+
+        assert!(VectorType::scalable(&[4], &[true], Type::float32(&context)).is_scalable());
+        assert!(!VectorType::new(&[4], Type::float32(&context)).is_scalable());
+    }
+
+    #[test]
+    fn is_dim_scalable() {
+        let context = Context::new();
+        let r#type = VectorType::scalable(&[4, 8], &[true, false], Type::float32(&context));
+
+        assert_eq!(r#type.is_dim_scalable(0), Ok(true));
+        assert_eq!(r#type.is_dim_scalable(1), Ok(false));
+        assert!(r#type.is_dim_scalable(2).is_err());
+        assert_eq!(r#type.scalable_dims(), [true, false]);
+    }
+
+    #[test]
+    fn shape() {
+        let context = Context::new();
+        let r#type = VectorType::new(&[2, 3], Type::float32(&context));
+
+        assert_eq!(r#type.element(), Type::float32(&context));
+        assert_eq!(r#type.rank(), 2);
+        assert_eq!(r#type.dim_size(1), Ok(DimSize::Static(3)));
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(VectorType::try_from(Type::index(&context)).is_err());
+    }
+}
@@ -303,4 +303,91 @@ mod tests {
 
         let _ = value.location();
     }
+
+    #[test]
+    fn uses() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+
+        let block = Block::new(&[(index_type, location)]);
+        let value = Value::from(block.argument(0).unwrap());
+
+        assert!(value.is_unused());
+        assert!(!value.has_one_use());
+
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[value])
+                .build()
+                .unwrap(),
+        );
+
+        assert!(!value.is_unused());
+        assert!(value.has_one_use());
+        assert_eq!(value.users().collect::<Vec<_>>(), [operation]);
+
+        block.append_operation(
+            OperationBuilder::new("bar", location)
+                .add_operands(&[value])
+                .build()
+                .unwrap(),
+        );
+
+        assert!(!value.has_one_use());
+        assert_eq!(value.uses().count(), 2);
+    }
+
+    #[test]
+    fn replace_all_uses_with() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+
+        let block = Block::new(&[(index_type, location), (index_type, location)]);
+        let value = Value::from(block.argument(0).unwrap());
+        let other = Value::from(block.argument(1).unwrap());
+
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[value])
+                .build()
+                .unwrap(),
+        );
+
+        value.replace_all_uses_with(other);
+
+        assert!(value.is_unused());
+        assert_eq!(operation.operand(0).unwrap(), other);
+    }
+
+    #[test]
+    fn replace_all_uses_except() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+
+        let block = Block::new(&[(index_type, location), (index_type, location)]);
+        let value = Value::from(block.argument(0).unwrap());
+        let other = Value::from(block.argument(1).unwrap());
+
+        let operation = || {
+            block.append_operation(
+                OperationBuilder::new("foo", location)
+                    .add_operands(&[value])
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let one = operation();
+        let another = operation();
+
+        value.replace_all_uses_except(other, &[one]);
+
+        assert_eq!(one.operand(0).unwrap(), value);
+        assert_eq!(another.operand(0).unwrap(), other);
+    }
 }
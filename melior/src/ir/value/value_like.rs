@@ -1,12 +1,14 @@
 use super::Type;
 use crate::{
     ContextRef,
-    ir::{Location, TypeLike},
+    ir::{Location, OperationRef, TypeLike, operation::OpOperand},
 };
 use mlir_sys::{
-    MlirValue, mlirValueDump, mlirValueGetContext, mlirValueGetLocation, mlirValueGetType,
-    mlirValueIsABlockArgument, mlirValueIsAOpResult, mlirValueSetType,
+    MlirValue, mlirValueDump, mlirValueGetContext, mlirValueGetFirstUse, mlirValueGetLocation,
+    mlirValueGetType, mlirValueIsABlockArgument, mlirValueIsAOpResult,
+    mlirValueReplaceAllUsesExcept, mlirValueReplaceAllUsesOfWith, mlirValueSetType,
 };
+use std::iter::successors;
 
 /// A trait for value-like types.
 pub trait ValueLike<'c> {
@@ -35,7 +37,60 @@ pub trait ValueLike<'c> {
 
     // TODO: expose mlirValuePrintAsOperand once MlirAsmState is available.
 
-    // TODO: expose mlirValueGetFirstUse once MlirOpOperand wrapper type exists.
+    /// Returns the first use of a value.
+    fn first_use(&self) -> Option<OpOperand<'c, '_>> {
+        unsafe { OpOperand::from_option_raw(mlirValueGetFirstUse(self.to_raw())) }
+    }
+
+    /// Returns all uses of a value.
+    fn uses(&self) -> impl Iterator<Item = OpOperand<'c, '_>> {
+        successors(self.first_use(), OpOperand::next_use)
+    }
+
+    /// Returns all operations using a value.
+    ///
+    /// An operation using a value more than once is returned for each use.
+    fn users(&self) -> impl Iterator<Item = OperationRef<'c, '_>> {
+        self.uses().map(|operand| operand.owner())
+    }
+
+    /// Returns `true` if a value has exactly one use.
+    fn has_one_use(&self) -> bool {
+        self.first_use()
+            .is_some_and(|operand| operand.next_use().is_none())
+    }
+
+    /// Returns `true` if a value has no uses.
+    fn is_unused(&self) -> bool {
+        self.first_use().is_none()
+    }
+
+    /// Replaces all uses of a value with another value.
+    fn replace_all_uses_with(&self, value: impl ValueLike<'c>) {
+        unsafe { mlirValueReplaceAllUsesOfWith(self.to_raw(), value.to_raw()) }
+    }
+
+    /// Replaces all uses of a value with another value except the ones in the
+    /// given operations.
+    fn replace_all_uses_except(
+        &self,
+        value: impl ValueLike<'c>,
+        exceptions: &[OperationRef<'c, '_>],
+    ) {
+        let exceptions = exceptions
+            .iter()
+            .map(|operation| operation.to_raw())
+            .collect::<Vec<_>>();
+
+        unsafe {
+            mlirValueReplaceAllUsesExcept(
+                self.to_raw(),
+                value.to_raw(),
+                exceptions.len() as isize,
+                exceptions.as_ptr() as *mut _,
+            )
+        }
+    }
 
     /// Returns `true` if a value is a block argument.
     fn is_block_argument(&self) -> bool {
@@ -1,6 +1,11 @@
 use crate::{
     context::{Context, ContextRef},
-    ir::{BlockLike, BlockRef, Operation, OperationRef, RegionLike, RegionRef, Value, ValueLike},
+    ir::{
+        BlockLike, BlockRef, Location, Operation, OperationRef, RegionLike, RegionRef, Value,
+        ValueLike,
+        operation::{OperationLike, WalkOrder, WalkResult},
+    },
+    rewriter_listener::RewriterListener,
 };
 use mlir_sys::{
     MlirRewriterBase, MlirValue, mlirIRRewriterCreate, mlirIRRewriterCreateFromOp,
@@ -15,11 +20,12 @@ use mlir_sys::{
     mlirRewriterBaseSetInsertionPointBefore, mlirRewriterBaseSetInsertionPointToEnd,
     mlirRewriterBaseSetInsertionPointToStart, mlirRewriterBaseStartOpModification,
 };
-use std::marker::PhantomData;
+use std::{cell::RefCell, marker::PhantomData};
 
 /// An IR rewriter. Owns the underlying rewriter object.
 pub struct IrRewriter<'c> {
     raw: MlirRewriterBase,
+    listener: Option<Box<RefCell<dyn RewriterListener + 'c>>>,
     _context: PhantomData<&'c Context>,
 }
 
@@ -28,6 +34,7 @@ impl<'c> IrRewriter<'c> {
     pub fn new(context: &'c Context) -> Self {
         Self {
             raw: unsafe { mlirIRRewriterCreate(context.to_raw()) },
+            listener: None,
             _context: Default::default(),
         }
     }
@@ -36,13 +43,19 @@ impl<'c> IrRewriter<'c> {
     pub fn from_op(op: OperationRef<'c, '_>) -> Self {
         Self {
             raw: unsafe { mlirIRRewriterCreateFromOp(op.to_raw()) },
+            listener: None,
             _context: Default::default(),
         }
     }
 
+    /// Sets a listener notified of changes made through the rewriter.
+    pub fn set_listener(&mut self, listener: impl RewriterListener + 'c) {
+        self.listener = Some(Box::new(RefCell::new(listener)));
+    }
+
     /// Returns the underlying rewriter base.
     pub fn as_rewriter_base(&self) -> RewriterBase<'c, '_> {
-        unsafe { RewriterBase::from_raw(self.raw) }
+        unsafe { RewriterBase::from_raw(self.raw) }.with_listener(self.listener.as_deref())
     }
 }
 
@@ -56,6 +69,7 @@ impl Drop for IrRewriter<'_> {
 #[derive(Clone, Copy)]
 pub struct RewriterBase<'c, 'a> {
     raw: MlirRewriterBase,
+    listener: Option<&'a RefCell<dyn RewriterListener + 'c>>,
     _context: PhantomData<&'c Context>,
     _reference: PhantomData<&'a ()>,
 }
@@ -69,11 +83,19 @@ impl<'c, 'a> RewriterBase<'c, 'a> {
     pub unsafe fn from_raw(raw: MlirRewriterBase) -> Self {
         Self {
             raw,
+            listener: None,
             _context: PhantomData,
             _reference: PhantomData,
         }
     }
 
+    pub(crate) fn with_listener(
+        self,
+        listener: Option<&'a RefCell<dyn RewriterListener + 'c>>,
+    ) -> Self {
+        Self { listener, ..self }
+    }
+
     /// Returns the context.
     pub fn context(&self) -> ContextRef<'c> {
         unsafe { ContextRef::from_raw(mlirRewriterBaseGetContext(self.raw)) }
@@ -117,34 +139,57 @@ impl<'c, 'a> RewriterBase<'c, 'a> {
     /// Inserts the operation at the current insertion point and returns a
     /// reference to it.
     pub fn insert(&self, op: Operation<'c>) -> OperationRef<'c, '_> {
-        unsafe { OperationRef::from_raw(mlirRewriterBaseInsert(self.raw, op.into_raw())) }
+        let op = unsafe { OperationRef::from_raw(mlirRewriterBaseInsert(self.raw, op.into_raw())) };
+
+        self.notify(|listener| listener.notify_operation_inserted(op));
+
+        op
     }
 
     /// Creates a deep copy of the operation.
     pub fn clone_op<'b>(&self, op: OperationRef<'c, 'b>) -> OperationRef<'c, 'b> {
-        unsafe { OperationRef::from_raw(mlirRewriterBaseClone(self.raw, op.to_raw())) }
+        let op = unsafe { OperationRef::from_raw(mlirRewriterBaseClone(self.raw, op.to_raw())) };
+
+        self.notify(|listener| listener.notify_operation_inserted(op));
+
+        op
     }
 
     /// Creates a deep copy of the operation without its regions.
     pub fn clone_op_without_regions<'b>(&self, op: OperationRef<'c, 'b>) -> OperationRef<'c, 'b> {
-        unsafe {
+        let op = unsafe {
             OperationRef::from_raw(mlirRewriterBaseCloneWithoutRegions(self.raw, op.to_raw()))
-        }
+        };
+
+        self.notify(|listener| listener.notify_operation_inserted(op));
+
+        op
     }
 
     /// Clones the blocks of the region before the given block.
     pub fn clone_region_before(&self, region: RegionRef, before: BlockRef) {
+        let previous = self.previous_block(before);
+
         unsafe { mlirRewriterBaseCloneRegionBefore(self.raw, region.to_raw(), before.to_raw()) }
+
+        self.notify_blocks_inserted(previous, before);
     }
 
     /// Moves the blocks of the region before the given block.
     pub fn inline_region_before(&self, region: RegionRef, before: BlockRef) {
+        let previous = self.previous_block(before);
+
         unsafe { mlirRewriterBaseInlineRegionBefore(self.raw, region.to_raw(), before.to_raw()) }
+
+        self.notify_blocks_inserted(previous, before);
     }
 
     /// Replaces the results of the operation with the given values. Erases the
     /// op.
     pub fn replace_op_with_values(&self, op: OperationRef, values: &[Value]) {
+        self.notify(|listener| listener.notify_operation_replaced(op, values));
+        self.notify_erased(op);
+
         unsafe {
             mlirRewriterBaseReplaceOpWithValues(
                 self.raw,
@@ -157,27 +202,52 @@ impl<'c, 'a> RewriterBase<'c, 'a> {
 
     /// Replaces the operation with another operation. Erases the original op.
     pub fn replace_op_with_operation(&self, op: OperationRef, new_op: OperationRef) {
+        if self.listener.is_some() {
+            let values = new_op
+                .results()
+                .map(|result| result.into())
+                .collect::<Vec<Value>>();
+
+            self.notify(|listener| listener.notify_operation_replaced(op, &values));
+            self.notify_erased(op);
+        }
+
         unsafe { mlirRewriterBaseReplaceOpWithOperation(self.raw, op.to_raw(), new_op.to_raw()) }
     }
 
     /// Erases the operation. The operation must have no uses.
     pub fn erase_op(&self, op: OperationRef) {
+        self.notify_erased(op);
+
         unsafe { mlirRewriterBaseEraseOp(self.raw, op.to_raw()) }
     }
 
     /// Erases the block along with all its operations.
     pub fn erase_block(&self, block: BlockRef) {
+        if self.listener.is_some() {
+            let mut op = block.first_operation();
+
+            while let Some(current) = op {
+                op = current.next_in_block();
+                self.notify_erased(current);
+            }
+        }
+
         unsafe { mlirRewriterBaseEraseBlock(self.raw, block.to_raw()) }
     }
 
     /// Moves the operation immediately before the existing operation.
     pub fn move_op_before(&self, op: OperationRef, existing_op: OperationRef) {
         unsafe { mlirRewriterBaseMoveOpBefore(self.raw, op.to_raw(), existing_op.to_raw()) }
+
+        self.notify(|listener| listener.notify_operation_inserted(op));
     }
 
     /// Moves the operation immediately after the existing operation.
     pub fn move_op_after(&self, op: OperationRef, existing_op: OperationRef) {
         unsafe { mlirRewriterBaseMoveOpAfter(self.raw, op.to_raw(), existing_op.to_raw()) }
+
+        self.notify(|listener| listener.notify_operation_inserted(op));
     }
 
     /// Moves the block immediately before the existing block.
@@ -185,6 +255,8 @@ impl<'c, 'a> RewriterBase<'c, 'a> {
         unsafe {
             mlirRewriterBaseMoveBlockBefore(self.raw, block.to_raw(), existing_block.to_raw())
         }
+
+        self.notify_block_inserted(block);
     }
 
     /// Signals the start of an in-place modification of the operation.
@@ -195,16 +267,95 @@ impl<'c, 'a> RewriterBase<'c, 'a> {
     /// Signals the end of an in-place modification of the operation.
     pub fn finalize_op_modification(&self, op: OperationRef) {
         unsafe { mlirRewriterBaseFinalizeOpModification(self.raw, op.to_raw()) }
+
+        self.notify(|listener| listener.notify_operation_modified(op));
     }
 
     /// Cancels a pending in-place modification of the operation.
+    ///
+    /// The operation is notified as modified as changes made since the start
+    /// of the modification are not rolled back.
     pub fn cancel_op_modification(&self, op: OperationRef) {
         unsafe { mlirRewriterBaseCancelOpModification(self.raw, op.to_raw()) }
+
+        self.notify(|listener| listener.notify_operation_modified(op));
     }
 
     /// Replaces all uses of `from` with `to`.
     pub fn replace_all_uses_with(&self, from: Value, to: Value) {
+        let users = if self.listener.is_some() {
+            from.uses()
+                .map(|operand| operand.owner().to_raw())
+                .collect()
+        } else {
+            vec![]
+        };
+
         unsafe { mlirRewriterBaseReplaceAllUsesWith(self.raw, from.to_raw(), to.to_raw()) }
+
+        for user in users {
+            self.notify(|listener| {
+                listener.notify_operation_modified(unsafe { OperationRef::from_raw(user) })
+            });
+        }
+    }
+
+    /// Notifies a listener that a pattern failed to match.
+    pub fn notify_match_failure(&self, location: Location, message: &str) {
+        self.notify(|listener| listener.notify_match_failure(location, message));
+    }
+
+    fn notify(&self, notify: impl FnOnce(&mut dyn RewriterListener)) {
+        if let Some(listener) = self.listener {
+            notify(&mut *listener.borrow_mut());
+        }
+    }
+
+    fn notify_erased(&self, op: OperationRef) {
+        if self.listener.is_some() {
+            op.walk(WalkOrder::PostOrder, |op| {
+                self.notify(|listener| listener.notify_operation_erased(op));
+                WalkResult::Advance
+            });
+        }
+    }
+
+    fn previous_block<'b>(&self, block: BlockRef<'c, 'b>) -> Option<BlockRef<'c, 'b>> {
+        self.listener
+            .is_some()
+            .then(|| block.previous_in_region())
+            .flatten()
+    }
+
+    // Notifies operations in blocks between the previous block and the given
+    // block, which are ones newly placed before the given block.
+    fn notify_blocks_inserted(&self, previous: Option<BlockRef>, before: BlockRef) {
+        if self.listener.is_none() {
+            return;
+        }
+
+        let mut block = match previous {
+            Some(previous) => previous.next_in_region(),
+            None => before
+                .parent_region()
+                .and_then(|region| region.first_block()),
+        };
+
+        while let Some(current) = block.filter(|block| block.to_raw().ptr != before.to_raw().ptr) {
+            block = current.next_in_region();
+            self.notify_block_inserted(current);
+        }
+    }
+
+    fn notify_block_inserted(&self, block: BlockRef) {
+        if self.listener.is_some() {
+            for op in block.operations() {
+                op.walk(WalkOrder::PreOrder, |op| {
+                    self.notify(|listener| listener.notify_operation_inserted(op));
+                    WalkResult::Advance
+                });
+            }
+        }
     }
 }
 
@@ -5,8 +5,10 @@ extern crate self as melior;
 #[macro_use]
 mod r#macro;
 mod context;
+pub mod conversion;
 pub mod diagnostic;
 pub mod dialect;
+mod dominance;
 mod error;
 mod execution_engine;
 mod greedy_rewrite_driver;
@@ -16,7 +18,9 @@ pub mod ir;
 mod ir_rewriter;
 mod logical_result;
 pub mod pass;
+mod pdl_pattern_module;
 mod rewrite_pattern;
+mod rewriter_listener;
 mod string_ref;
 mod thread_pool;
 
@@ -26,22 +30,25 @@ pub mod utility;
 
 pub use self::{
     context::{Context, ContextRef},
+    dominance::{DominanceInfo, PostDominanceInfo},
     error::Error,
     execution_engine::ExecutionEngine,
     greedy_rewrite_driver::{
         GreedyRewriteDriverConfig, GreedyRewriteStrictness, GreedySimplifyRegionLevel,
-        apply_patterns_and_fold_greedily, walk_and_apply_patterns,
+        apply_op_patterns_greedily, apply_patterns_and_fold_greedily, walk_and_apply_patterns,
     },
     ir_rewriter::{IrRewriter, RewriterBase},
+    pdl_pattern_module::{PdlPatternModule, PdlResultList, PdlValue},
     rewrite_pattern::{
-        FrozenRewritePatternSet, PatternRewriter, RewritePattern, RewritePatternSet,
-        create_op_rewrite_pattern,
+        FrozenRewritePatternSet, OpRewritePattern, PatternRewriter, RewritePattern,
+        RewritePatternSet, create_op_rewrite_pattern,
     },
+    rewriter_listener::RewriterListener,
     string_ref::StringRef,
     thread_pool::ThreadPool,
 };
 
-pub use melior_macro::dialect;
+pub use melior_macro::{dialect, rewrite};
 
 #[cfg(test)]
 mod tests {
@@ -2,6 +2,7 @@ use super::OperationPassManager;
 use crate::{
     Error,
     context::Context,
+    diagnostic::observe_diagnostics,
     ir::{Module, operation::OperationPrintingFlags},
     logical_result::LogicalResult,
     pass::Pass,
@@ -69,15 +70,25 @@ impl<'c> PassManager<'c> {
     }
 
     /// Runs passes added to a pass manager against a module.
+    ///
+    /// On failure, an error contains diagnostics emitted by the passes. The
+    /// diagnostics are still passed on to diagnostic handlers attached to the
+    /// context. Note that the C API does not tell which pass failed. Failing
+    /// passes usually emit errors located at the operations they failed on.
     pub fn run(&self, module: &mut Module) -> Result<(), Error> {
-        let result = LogicalResult::from_raw(unsafe {
-            mlirPassManagerRunOnOp(self.raw, module.as_operation().to_raw())
+        let context = module.context();
+        let (result, diagnostics) = observe_diagnostics(unsafe { context.to_ref() }, || {
+            LogicalResult::from_raw(unsafe {
+                mlirPassManagerRunOnOp(self.raw, module.as_operation().to_raw())
+            })
         });
 
         if result.is_success() {
             Ok(())
         } else {
-            Err(Error::RunPass)
+            Err(Error::RunPass(
+                diagnostics.into_iter().map(Into::into).collect(),
+            ))
         }
     }
 
@@ -131,7 +142,8 @@ pub struct PassIrPrintingOptions {
 mod tests {
     use super::*;
     use crate::{
-        ir::{Location, Module},
+        diagnostic::DiagnosticSeverity,
+        ir::{BlockLike, Location, Module, Type, operation::OperationBuilder},
         pass::{self, transform::register_print_op_stats},
         test::create_test_context,
         utility::parse_pass_pipeline,
@@ -179,6 +191,30 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn run_error() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let mut module = Module::new(location);
+
+        module.body().append_operation(
+            OperationBuilder::new("arith.addi", location)
+                .add_results(&[Type::index(&context)])
+                .build()
+                .unwrap(),
+        );
+
+        let manager = PassManager::new(&context);
+        manager.add_pass(pass::transform::create_cse());
+
+        let Err(Error::RunPass(diagnostics)) = manager.run(&mut module) else {
+            panic!("pass error expected");
+        };
+
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+        assert_eq!(diagnostics[0].location(), location.to_string());
+    }
+
     #[test]
     fn run_on_function() {
         let context = create_test_context();
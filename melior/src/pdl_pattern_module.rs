@@ -0,0 +1,304 @@
+use crate::{
+    context::Context,
+    ir::{Attribute, AttributeLike, Module, OperationRef, Type, TypeLike, Value, ValueLike},
+    logical_result::LogicalResult,
+    rewrite_pattern::{PatternRewriter, RewritePatternSet},
+    string_ref::StringRef,
+};
+use mlir_sys::{
+    MlirLogicalResult, MlirPDLPatternModule, MlirPDLResultList, MlirPDLValue, MlirPatternRewriter,
+    mlirPDLPatternModuleDestroy, mlirPDLPatternModuleFromModule,
+    mlirPDLPatternModuleRegisterConstraintFunction, mlirPDLPatternModuleRegisterRewriteFunction,
+    mlirPDLResultListPushBackAttribute, mlirPDLResultListPushBackOperation,
+    mlirPDLResultListPushBackType, mlirPDLResultListPushBackValue, mlirPDLValueAsAttribute,
+    mlirPDLValueAsOperation, mlirPDLValueAsType, mlirPDLValueAsValue,
+    mlirRewritePatternSetFromPDLPatternModule,
+};
+use std::{any::Any, ffi::c_void, marker::PhantomData, mem::take, slice};
+
+/// A PDL pattern module.
+///
+/// It compiles a module of `pdl.pattern` operations into rewrite patterns.
+/// Native constraints and rewrites called by the patterns with
+/// `pdl.apply_native_constraint` and `pdl.apply_native_rewrite` are
+/// registered as Rust closures.
+pub struct PdlPatternModule<'c> {
+    raw: MlirPDLPatternModule,
+    context: &'c Context,
+    native_functions: Vec<Box<dyn Any>>,
+}
+
+impl<'c> PdlPatternModule<'c> {
+    /// Creates a PDL pattern module. The module's ownership is transferred.
+    pub fn new(module: Module<'c>) -> Self {
+        let context = unsafe { module.context().to_ref() };
+
+        Self {
+            raw: unsafe { mlirPDLPatternModuleFromModule(module.into_raw()) },
+            context,
+            native_functions: vec![],
+        }
+    }
+
+    /// Registers a native constraint function.
+    ///
+    /// It receives values passed by a `pdl.apply_native_constraint` operation
+    /// and returns `true` if the constraint is satisfied.
+    pub fn register_constraint_function<F>(&mut self, name: &str, function: F)
+    where
+        F: FnMut(&PatternRewriter, &mut PdlResultList, &[PdlValue<'c, '_>]) -> bool + 'static,
+    {
+        let user_data = self.add_native_function(function);
+
+        unsafe {
+            mlirPDLPatternModuleRegisterConstraintFunction(
+                self.raw,
+                StringRef::new(name).to_raw(),
+                Some(call_native_function::<F>),
+                user_data,
+            )
+        }
+    }
+
+    /// Registers a native rewrite function.
+    ///
+    /// It receives values passed by a `pdl.apply_native_rewrite` operation,
+    /// pushes its results to a result list, and returns `true` on success.
+    pub fn register_rewrite_function<F>(&mut self, name: &str, function: F)
+    where
+        F: FnMut(&PatternRewriter, &mut PdlResultList, &[PdlValue<'c, '_>]) -> bool + 'static,
+    {
+        let user_data = self.add_native_function(function);
+
+        unsafe {
+            mlirPDLPatternModuleRegisterRewriteFunction(
+                self.raw,
+                StringRef::new(name).to_raw(),
+                Some(call_native_function::<F>),
+                user_data,
+            )
+        }
+    }
+
+    /// Converts a PDL pattern module into a rewrite pattern set.
+    pub fn into_rewrite_pattern_set(mut self) -> RewritePatternSet<'c> {
+        unsafe {
+            RewritePatternSet::from_raw(
+                mlirRewritePatternSetFromPDLPatternModule(self.raw),
+                self.context,
+                take(&mut self.native_functions),
+            )
+        }
+    }
+
+    fn add_native_function<F: 'static>(&mut self, function: F) -> *mut c_void {
+        let mut function = Box::new(function);
+        let user_data = function.as_mut() as *mut F as *mut c_void;
+
+        self.native_functions.push(function);
+
+        user_data
+    }
+}
+
+impl Drop for PdlPatternModule<'_> {
+    fn drop(&mut self) {
+        unsafe { mlirPDLPatternModuleDestroy(self.raw) }
+    }
+}
+
+unsafe extern "C" fn call_native_function<'c, F>(
+    rewriter: MlirPatternRewriter,
+    results: MlirPDLResultList,
+    value_count: usize,
+    values: *mut MlirPDLValue,
+    user_data: *mut c_void,
+) -> MlirLogicalResult
+where
+    F: FnMut(&PatternRewriter, &mut PdlResultList, &[PdlValue<'c, '_>]) -> bool,
+{
+    let function = unsafe { &mut *(user_data as *mut F) };
+    let values: &[PdlValue] = if value_count == 0 {
+        &[]
+    } else {
+        unsafe { slice::from_raw_parts(values as *const PdlValue, value_count) }
+    };
+
+    LogicalResult::from(function(
+        &unsafe { PatternRewriter::from_raw(rewriter) },
+        &mut unsafe { PdlResultList::from_raw(results) },
+        values,
+    ))
+    .to_raw()
+}
+
+/// A value passed to a native PDL function.
+///
+/// It is one of a value, a type, an operation, or an attribute.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct PdlValue<'c, 'a> {
+    raw: MlirPDLValue,
+    _context: PhantomData<&'c Context>,
+    _reference: PhantomData<&'a ()>,
+}
+
+impl<'c, 'a> PdlValue<'c, 'a> {
+    /// Returns a value if it is a value.
+    pub fn as_value(&self) -> Option<Value<'c, 'a>> {
+        let raw = unsafe { mlirPDLValueAsValue(self.raw) };
+
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Value::from_raw(raw) })
+        }
+    }
+
+    /// Returns a type if it is a type.
+    pub fn as_type(&self) -> Option<Type<'c>> {
+        unsafe { Type::from_option_raw(mlirPDLValueAsType(self.raw)) }
+    }
+
+    /// Returns an operation if it is an operation.
+    pub fn as_operation(&self) -> Option<OperationRef<'c, 'a>> {
+        unsafe { OperationRef::from_option_raw(mlirPDLValueAsOperation(self.raw)) }
+    }
+
+    /// Returns an attribute if it is an attribute.
+    pub fn as_attribute(&self) -> Option<Attribute<'c>> {
+        unsafe { Attribute::from_option_raw(mlirPDLValueAsAttribute(self.raw)) }
+    }
+}
+
+/// A result list of a native PDL function.
+pub struct PdlResultList {
+    raw: MlirPDLResultList,
+}
+
+impl PdlResultList {
+    unsafe fn from_raw(raw: MlirPDLResultList) -> Self {
+        Self { raw }
+    }
+
+    /// Pushes a value.
+    pub fn push_value<'c>(&mut self, value: impl ValueLike<'c>) {
+        unsafe { mlirPDLResultListPushBackValue(self.raw, value.to_raw()) }
+    }
+
+    /// Pushes a type.
+    pub fn push_type<'c>(&mut self, r#type: impl TypeLike<'c>) {
+        unsafe { mlirPDLResultListPushBackType(self.raw, r#type.to_raw()) }
+    }
+
+    /// Pushes an operation.
+    pub fn push_operation(&mut self, operation: OperationRef) {
+        unsafe { mlirPDLResultListPushBackOperation(self.raw, operation.to_raw()) }
+    }
+
+    /// Pushes an attribute.
+    pub fn push_attribute<'c>(&mut self, attribute: impl AttributeLike<'c>) {
+        unsafe { mlirPDLResultListPushBackAttribute(self.raw, attribute.to_raw()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        greedy_rewrite_driver::{GreedyRewriteDriverConfig, apply_patterns_and_fold_greedily},
+        ir::{BlockLike, operation::OperationLike},
+        test::create_test_context,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    const SOURCE: &str = r#"
+        "foo.bar"() : () -> ()
+        "foo.baz"() : () -> ()
+    "#;
+
+    #[test]
+    fn apply() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let patterns = PdlPatternModule::new(
+            Module::parse(
+                &context,
+                r#"
+                pdl.pattern : benefit(1) {
+                  %root = pdl.operation "foo.bar"
+                  pdl.rewrite %root {
+                    pdl.erase %root
+                  }
+                }
+                "#,
+            )
+            .unwrap(),
+        )
+        .into_rewrite_pattern_set();
+        let module = Module::parse(&context, SOURCE).unwrap();
+
+        apply_patterns_and_fold_greedily(
+            &module,
+            patterns.freeze(),
+            &GreedyRewriteDriverConfig::new(),
+        )
+        .unwrap();
+
+        assert_eq!(module.body().operations().count(), 1);
+    }
+
+    #[test]
+    fn apply_native_functions() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let mut patterns = PdlPatternModule::new(
+            Module::parse(
+                &context,
+                r#"
+                pdl.pattern : benefit(1) {
+                  %root = pdl.operation
+                  pdl.apply_native_constraint "is_baz"(%root : !pdl.operation)
+                  pdl.rewrite %root {
+                    pdl.apply_native_rewrite "erase"(%root : !pdl.operation)
+                  }
+                }
+                "#,
+            )
+            .unwrap(),
+        );
+        let count = Rc::new(Cell::new(0));
+
+        patterns.register_constraint_function("is_baz", |_, _, values| {
+            values[0]
+                .as_operation()
+                .map(|operation| operation.name().as_string_ref().as_str() == Ok("foo.baz"))
+                .unwrap_or_default()
+        });
+        patterns.register_rewrite_function("erase", {
+            let count = count.clone();
+
+            move |rewriter, _, values| {
+                count.set(count.get() + 1);
+                rewriter
+                    .as_rewriter_base()
+                    .erase_op(values[0].as_operation().unwrap());
+                true
+            }
+        });
+
+        let module = Module::parse(&context, SOURCE).unwrap();
+
+        apply_patterns_and_fold_greedily(
+            &module,
+            patterns.into_rewrite_pattern_set().freeze(),
+            &GreedyRewriteDriverConfig::new(),
+        )
+        .unwrap();
+
+        assert_eq!(count.get(), 1);
+        assert_eq!(module.body().operations().count(), 1);
+    }
+}
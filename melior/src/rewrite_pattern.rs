@@ -1,6 +1,6 @@
 use crate::{
-    context::Context, ir_rewriter::RewriterBase, logical_result::LogicalResult,
-    string_ref::StringRef,
+    context::Context, ir::OperationRef, ir_rewriter::RewriterBase, logical_result::LogicalResult,
+    rewriter_listener::active_listener, string_ref::StringRef,
 };
 use mlir_sys::{
     MlirFrozenRewritePatternSet, MlirOperation, MlirPatternRewriter, MlirRewritePattern,
@@ -8,12 +8,18 @@ use mlir_sys::{
     mlirFrozenRewritePatternSetDestroy, mlirOpRewritePatternCreate, mlirPatternRewriterAsBase,
     mlirRewritePatternSetAdd, mlirRewritePatternSetCreate, mlirRewritePatternSetDestroy,
 };
-use std::{ffi::c_void, marker::PhantomData, mem::forget};
+use std::{
+    any::Any,
+    ffi::c_void,
+    mem::{forget, take},
+};
 
 /// A set of rewrite patterns.
 pub struct RewritePatternSet<'c> {
     raw: MlirRewritePatternSet,
-    _context: PhantomData<&'c Context>,
+    context: &'c Context,
+    // Native functions referenced by patterns, such as ones of PDL.
+    native_functions: Vec<Box<dyn Any>>,
 }
 
 impl<'c> RewritePatternSet<'c> {
@@ -21,7 +27,20 @@ impl<'c> RewritePatternSet<'c> {
     pub fn new(context: &'c Context) -> Self {
         Self {
             raw: unsafe { mlirRewritePatternSetCreate(context.to_raw()) },
-            _context: Default::default(),
+            context,
+            native_functions: vec![],
+        }
+    }
+
+    pub(crate) unsafe fn from_raw(
+        raw: MlirRewritePatternSet,
+        context: &'c Context,
+        native_functions: Vec<Box<dyn Any>>,
+    ) -> Self {
+        Self {
+            raw,
+            context,
+            native_functions,
         }
     }
 
@@ -30,13 +49,34 @@ impl<'c> RewritePatternSet<'c> {
         unsafe { mlirRewritePatternSetAdd(self.raw, pattern.into_raw()) }
     }
 
+    /// Adds an operation rewrite pattern matching operations with a root
+    /// name.
+    pub fn add_pattern(
+        &self,
+        root_name: &str,
+        benefit: u32,
+        pattern: impl OpRewritePattern<'c> + 'static,
+    ) {
+        self.add(RewritePattern::new(
+            self.context,
+            root_name,
+            benefit,
+            &[],
+            pattern,
+        ));
+    }
+
     /// Freezes the pattern set into a frozen set. Consumes self.
-    pub fn freeze(self) -> FrozenRewritePatternSet {
+    pub fn freeze(mut self) -> FrozenRewritePatternSet {
         let raw = unsafe { mlirFreezeRewritePattern(self.raw) };
+        let native_functions = take(&mut self.native_functions);
 
         forget(self);
 
-        FrozenRewritePatternSet { raw }
+        FrozenRewritePatternSet {
+            raw,
+            _native_functions: native_functions,
+        }
     }
 }
 
@@ -49,11 +89,15 @@ impl Drop for RewritePatternSet<'_> {
 /// A frozen (immutable) rewrite pattern set.
 pub struct FrozenRewritePatternSet {
     raw: MlirFrozenRewritePatternSet,
+    _native_functions: Vec<Box<dyn Any>>,
 }
 
 impl FrozenRewritePatternSet {
     /// Converts the frozen pattern set into a raw object, transferring
     /// ownership.
+    ///
+    /// Native functions referenced by the patterns are leaked so that they
+    /// live as long as the raw object.
     pub fn into_raw(self) -> MlirFrozenRewritePatternSet {
         let raw = self.raw;
 
@@ -76,12 +120,62 @@ pub struct RewritePattern {
 }
 
 impl RewritePattern {
+    /// Creates a rewrite pattern from an operation rewrite pattern matching
+    /// operations with a root name.
+    ///
+    /// `generated_names` are names of operations the pattern might generate.
+    pub fn new<'c>(
+        context: &'c Context,
+        root_name: &str,
+        benefit: u32,
+        generated_names: &[&str],
+        mut pattern: impl OpRewritePattern<'c> + 'static,
+    ) -> Self {
+        create_op_rewrite_pattern(
+            root_name,
+            benefit,
+            context,
+            move |_, operation, rewriter| {
+                pattern.match_and_rewrite(unsafe { OperationRef::from_raw(operation) }, &unsafe {
+                    PatternRewriter::from_raw(rewriter)
+                })
+            },
+            generated_names,
+        )
+    }
+
     /// Converts the pattern into a raw object, transferring ownership.
     pub fn into_raw(self) -> MlirRewritePattern {
         self.raw
     }
 }
 
+/// An operation rewrite pattern.
+///
+/// Closures of `FnMut(OperationRef, &PatternRewriter) -> bool` implement this
+/// trait.
+pub trait OpRewritePattern<'c> {
+    /// Matches an operation and rewrites it.
+    ///
+    /// It returns `true` if the operation is rewritten. On failure, it must
+    /// not modify IR.
+    fn match_and_rewrite(
+        &mut self,
+        operation: OperationRef<'c, '_>,
+        rewriter: &PatternRewriter,
+    ) -> bool;
+}
+
+impl<'c, F: FnMut(OperationRef<'c, '_>, &PatternRewriter) -> bool> OpRewritePattern<'c> for F {
+    fn match_and_rewrite(
+        &mut self,
+        operation: OperationRef<'c, '_>,
+        rewriter: &PatternRewriter,
+    ) -> bool {
+        self(operation, rewriter)
+    }
+}
+
 /// A pattern rewriter available inside a match-and-rewrite callback.
 ///
 /// This is a non-owning reference; it must not outlive the callback invocation.
@@ -101,8 +195,13 @@ impl PatternRewriter {
     }
 
     /// Returns the underlying rewriter base.
+    ///
+    /// It notifies a listener of a driver run it belongs to, if any.
     pub fn as_rewriter_base(&self) -> RewriterBase<'_, '_> {
-        unsafe { RewriterBase::from_raw(mlirPatternRewriterAsBase(self.raw)) }
+        unsafe {
+            RewriterBase::from_raw(mlirPatternRewriterAsBase(self.raw))
+                .with_listener(active_listener(self))
+        }
     }
 }
 
@@ -303,4 +402,282 @@ mod tests {
 
         assert!(apply_patterns_and_fold_greedily(&module, frozen, &config).is_ok());
     }
+
+    #[test]
+    fn add_pattern() {
+        use crate::{
+            dialect::arith,
+            ir::{BlockLike, Type, attribute::IntegerAttribute},
+        };
+
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+
+        module.body().append_operation(arith::constant(
+            &context,
+            IntegerAttribute::new(Type::index(&context), 0).into(),
+            location,
+        ));
+
+        let set = RewritePatternSet::new(&context);
+        set.add_pattern(
+            "arith.constant",
+            1,
+            |operation: OperationRef, rewriter: &PatternRewriter| {
+                rewriter.as_rewriter_base().erase_op(operation);
+                true
+            },
+        );
+
+        apply_patterns_and_fold_greedily(&module, set.freeze(), &GreedyRewriteDriverConfig::new())
+            .unwrap();
+
+        assert_eq!(module.body().first_operation(), None);
+    }
+
+    #[test]
+    fn add_pattern_struct() {
+        use crate::{
+            dialect::arith,
+            ir::{BlockLike, Type, attribute::IntegerAttribute},
+        };
+        use std::{cell::Cell, rc::Rc};
+
+        struct CountPattern(Rc<Cell<usize>>);
+
+        impl<'c> OpRewritePattern<'c> for CountPattern {
+            fn match_and_rewrite(
+                &mut self,
+                _operation: OperationRef<'c, '_>,
+                _rewriter: &PatternRewriter,
+            ) -> bool {
+                self.0.set(self.0.get() + 1);
+                false
+            }
+        }
+
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+
+        module.body().append_operation(arith::constant(
+            &context,
+            IntegerAttribute::new(Type::index(&context), 0).into(),
+            location,
+        ));
+
+        let count = Rc::new(Cell::new(0));
+        let set = RewritePatternSet::new(&context);
+        set.add_pattern("arith.constant", 1, CountPattern(count.clone()));
+
+        apply_patterns_and_fold_greedily(&module, set.freeze(), &GreedyRewriteDriverConfig::new())
+            .unwrap();
+
+        assert!(count.get() > 0);
+    }
+
+    #[test]
+    fn add_declarative_pattern() {
+        use crate::ir::operation::OperationLike;
+
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            r#"
+            func.func @foo(%arg0: i64) -> i64 {
+              %0 = arith.constant 2 : i64
+              %1 = arith.muli %arg0, %0 : i64
+              return %1 : i64
+            }
+            "#,
+        )
+        .unwrap();
+
+        let set = RewritePatternSet::new(&context);
+        set.add(crate::rewrite!(
+            &context,
+            (arith.muli $x, (arith.constant 2)) => (arith.addi $x, $x),
+        ));
+
+        apply_patterns_and_fold_greedily(&module, set.freeze(), &GreedyRewriteDriverConfig::new())
+            .unwrap();
+
+        let string = module.as_operation().to_string();
+
+        assert!(string.contains("arith.addi %arg0, %arg0"));
+        assert!(!string.contains("arith.muli"));
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn add_declarative_pattern_with_variable_result() {
+        use crate::ir::operation::OperationLike;
+
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            r#"
+            func.func @foo(%arg0: i64) -> i64 {
+              %0 = arith.constant 0 : i64
+              %1 = arith.addi %arg0, %0 : i64
+              return %1 : i64
+            }
+            "#,
+        )
+        .unwrap();
+
+        let set = RewritePatternSet::new(&context);
+        set.add(crate::rewrite!(
+            &context,
+            (arith.addi $x, (arith.constant 0)) => $x,
+        ));
+
+        apply_patterns_and_fold_greedily(&module, set.freeze(), &GreedyRewriteDriverConfig::new())
+            .unwrap();
+
+        let string = module.as_operation().to_string();
+
+        assert!(string.contains("return %arg0"));
+        assert!(!string.contains("arith.addi"));
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn add_declarative_pattern_with_attribute() {
+        use crate::ir::operation::OperationLike;
+
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            r#"
+            func.func @foo(%arg0: i64, %arg1: i64) -> (i1, i1) {
+              %0 = arith.cmpi eq, %arg0, %arg1 : i64
+              %1 = arith.cmpi slt, %arg0, %arg1 : i64
+              return %0, %1 : i1, i1
+            }
+            "#,
+        )
+        .unwrap();
+
+        let set = RewritePatternSet::new(&context);
+        set.add(crate::rewrite!(
+            &context,
+            (arith.cmpi {predicate = "0 : i64"} $x, $y) => (arith.cmpi {predicate = "1 : i64"} $y, $x),
+        ));
+
+        apply_patterns_and_fold_greedily(&module, set.freeze(), &GreedyRewriteDriverConfig::new())
+            .unwrap();
+
+        let string = module.as_operation().to_string();
+
+        assert!(string.contains("arith.cmpi ne, %arg1, %arg0"));
+        assert!(string.contains("arith.cmpi slt, %arg0, %arg1"));
+        assert!(!string.contains("arith.cmpi eq"));
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn add_declarative_pattern_with_benefit() {
+        use crate::ir::operation::OperationLike;
+
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            r#"
+            func.func @foo(%arg0: i64) -> i64 {
+              %0 = arith.constant 2 : i64
+              %1 = arith.muli %arg0, %0 : i64
+              return %1 : i64
+            }
+            "#,
+        )
+        .unwrap();
+
+        let set = RewritePatternSet::new(&context);
+        set.add(crate::rewrite!(
+            &context,
+            (arith.muli $x, (arith.constant 2)) => (arith.addi $x, $x),
+        ));
+        set.add(crate::rewrite!(
+            &context,
+            (arith.muli $x, (arith.constant 2)) => (arith.shli $x, (arith.constant 1 : i64)),
+            benefit = 2,
+        ));
+
+        apply_patterns_and_fold_greedily(&module, set.freeze(), &GreedyRewriteDriverConfig::new())
+            .unwrap();
+
+        let string = module.as_operation().to_string();
+
+        assert!(string.contains("arith.shli"));
+        assert!(!string.contains("arith.addi"));
+        assert!(!string.contains("arith.muli"));
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn add_declarative_pattern_with_typed_literal() {
+        use crate::ir::operation::OperationLike;
+
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            r#"
+            func.func @foo(%arg0: i32, %arg1: i32) -> i1 {
+              %0 = arith.cmpi eq, %arg0, %arg1 : i32
+              return %0 : i1
+            }
+            "#,
+        )
+        .unwrap();
+
+        let set = RewritePatternSet::new(&context);
+        set.add(crate::rewrite!(
+            &context,
+            (arith.cmpi {predicate = "0 : i64"} $x, $y)
+                => (arith.cmpi {predicate = "1 : i64"} (arith.subi $x, $y), (arith.constant 0 : i32)),
+        ));
+
+        apply_patterns_and_fold_greedily(&module, set.freeze(), &GreedyRewriteDriverConfig::new())
+            .unwrap();
+
+        let string = module.as_operation().to_string();
+
+        assert!(string.contains("arith.constant 0 : i32"));
+        assert!(string.contains("arith.subi %arg0, %arg1"));
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn add_declarative_pattern_with_invalid_result() {
+        use crate::ir::operation::OperationLike;
+
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            r#"
+            func.func @foo(%arg0: i64) -> i64 {
+              %0 = arith.muli %arg0, %arg0 : i64
+              return %0 : i64
+            }
+            "#,
+        )
+        .unwrap();
+
+        let set = RewritePatternSet::new(&context);
+        set.add(crate::rewrite!(
+            &context,
+            (arith.muli $x, $x) => (arith.addi (arith.constant 1 : i64), (foo.bar $x)),
+        ));
+
+        apply_patterns_and_fold_greedily(&module, set.freeze(), &GreedyRewriteDriverConfig::new())
+            .unwrap();
+
+        let string = module.as_operation().to_string();
+
+        assert!(string.contains("arith.muli %arg0, %arg0"));
+        assert!(!string.contains("arith.constant"));
+        assert!(module.as_operation().verify());
+    }
 }
@@ -0,0 +1,236 @@
+use crate::{
+    PatternRewriter,
+    ir::{Location, OperationRef, Value},
+};
+use std::cell::RefCell;
+
+thread_local! {
+    static ACTIVE_LISTENERS: RefCell<Vec<*const RefCell<dyn RewriterListener>>> =
+        const { RefCell::new(vec![]) };
+}
+
+/// A rewriter listener.
+///
+/// It is notified of changes made to IR through a rewriter. All methods do
+/// nothing by default.
+///
+/// Only changes made through rewriters exposed by this crate are notified.
+/// Operations folded or erased as dead code by the greedy rewrite driver itself
+/// and rewrites applied by PDL operations outside of registered rewrite
+/// functions are not visible to listeners.
+pub trait RewriterListener {
+    /// Notifies that an operation is inserted or moved.
+    fn notify_operation_inserted(&mut self, _operation: OperationRef) {}
+
+    /// Notifies that an operation is modified in place.
+    fn notify_operation_modified(&mut self, _operation: OperationRef) {}
+
+    /// Notifies that results of an operation are about to be replaced with
+    /// values.
+    fn notify_operation_replaced(&mut self, _operation: OperationRef, _values: &[Value]) {}
+
+    /// Notifies that an operation is about to be erased.
+    ///
+    /// Nested operations are notified before their parent operations.
+    fn notify_operation_erased(&mut self, _operation: OperationRef) {}
+
+    /// Notifies that a pattern failed to match.
+    fn notify_match_failure(&mut self, _location: Location, _message: &str) {}
+}
+
+// Listeners of pattern rewriters are not visible through the C API. Instead,
+// a listener is activated during a driver run and picked up by pattern
+// rewriters passed to pattern callbacks.
+pub(crate) fn with_active_listener<T>(
+    listener: Option<&RefCell<dyn RewriterListener>>,
+    function: impl FnOnce() -> T,
+) -> T {
+    let Some(listener) = listener else {
+        return function();
+    };
+
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            ACTIVE_LISTENERS.with_borrow_mut(|listeners| listeners.pop());
+        }
+    }
+
+    ACTIVE_LISTENERS.with_borrow_mut(|listeners| listeners.push(listener));
+    let _guard = Guard;
+
+    function()
+}
+
+/// Returns the innermost active listener for a pattern rewriter.
+///
+/// # Safety
+///
+/// A pattern rewriter must be the one passed to a pattern callback during a
+/// driver run activating the listener.
+pub(crate) unsafe fn active_listener<'a>(
+    _rewriter: &'a PatternRewriter,
+) -> Option<&'a RefCell<dyn RewriterListener + 'a>> {
+    ACTIVE_LISTENERS
+        .with_borrow(|listeners| listeners.last().copied())
+        .map(|listener| unsafe { &*(listener as *const RefCell<dyn RewriterListener + 'a>) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        GreedyRewriteDriverConfig, IrRewriter, PatternRewriter, RewritePatternSet,
+        apply_patterns_and_fold_greedily,
+        dialect::arith,
+        ir::{
+            BlockLike, Module, RegionLike, Type,
+            attribute::IntegerAttribute,
+            operation::{OperationBuilder, OperationLike},
+        },
+        test::create_test_context,
+    };
+    use std::rc::Rc;
+
+    #[derive(Clone, Default)]
+    struct EventListener(Rc<RefCell<Vec<String>>>);
+
+    impl EventListener {
+        fn push(&self, event: &str, operation: OperationRef) {
+            self.0.borrow_mut().push(format!(
+                "{event} {}",
+                operation.name().as_string_ref().as_str().unwrap()
+            ));
+        }
+    }
+
+    impl RewriterListener for EventListener {
+        fn notify_operation_inserted(&mut self, operation: OperationRef) {
+            self.push("inserted", operation);
+        }
+
+        fn notify_operation_modified(&mut self, operation: OperationRef) {
+            self.push("modified", operation);
+        }
+
+        fn notify_operation_replaced(&mut self, operation: OperationRef, _values: &[Value]) {
+            self.push("replaced", operation);
+        }
+
+        fn notify_operation_erased(&mut self, operation: OperationRef) {
+            self.push("erased", operation);
+        }
+
+        fn notify_match_failure(&mut self, _location: Location, message: &str) {
+            self.0.borrow_mut().push(format!("failed {message}"));
+        }
+    }
+
+    #[test]
+    fn notify_ir_rewriter() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+        let listener = EventListener::default();
+
+        let mut rewriter = IrRewriter::new(&context);
+        rewriter.set_listener(listener.clone());
+        let rewriter = rewriter.as_rewriter_base();
+        rewriter.set_insertion_point_to_end(module.body());
+
+        let r#type = Type::index(&context);
+        let constant = rewriter.insert(arith::constant(
+            &context,
+            IntegerAttribute::new(r#type, 1).into(),
+            location,
+        ));
+        let value = constant.result(0).unwrap().into();
+        let addition = rewriter.insert(arith::addi(value, value, location));
+
+        rewriter.start_op_modification(addition);
+        rewriter.finalize_op_modification(addition);
+        rewriter.notify_match_failure(location, "foo");
+        rewriter.replace_op_with_values(addition, &[value]);
+
+        assert_eq!(
+            *listener.0.borrow(),
+            [
+                "inserted arith.constant",
+                "inserted arith.addi",
+                "modified arith.addi",
+                "failed foo",
+                "replaced arith.addi",
+                "erased arith.addi",
+            ]
+        );
+    }
+
+    #[test]
+    fn notify_cloned_region() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            r#"
+            func.func @foo() {
+              %0 = arith.constant 1 : index
+              return
+            }
+
+            func.func @bar() {
+              return
+            }
+            "#,
+        )
+        .unwrap();
+        let listener = EventListener::default();
+
+        let mut rewriter = IrRewriter::new(&context);
+        rewriter.set_listener(listener.clone());
+
+        let mut functions = module.body().operations();
+        let foo = functions.next().unwrap().region(0).unwrap();
+        let bar = functions.next().unwrap().region(0).unwrap();
+
+        rewriter
+            .as_rewriter_base()
+            .clone_region_before(foo, bar.first_block().unwrap());
+
+        assert_eq!(bar.blocks().count(), 2);
+        assert_eq!(
+            *listener.0.borrow(),
+            ["inserted arith.constant", "inserted func.return"]
+        );
+    }
+
+    #[test]
+    fn notify_greedy_rewrite_driver() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+        let listener = EventListener::default();
+
+        module
+            .body()
+            .append_operation(OperationBuilder::new("foo.bar", location).build().unwrap());
+
+        let set = RewritePatternSet::new(&context);
+        set.add_pattern(
+            "foo.bar",
+            1,
+            |operation: OperationRef, rewriter: &PatternRewriter| {
+                rewriter.as_rewriter_base().erase_op(operation);
+                true
+            },
+        );
+
+        let mut config = GreedyRewriteDriverConfig::new();
+        config.set_listener(listener.clone());
+
+        apply_patterns_and_fold_greedily(&module, set.freeze(), &config).unwrap();
+
+        assert_eq!(*listener.0.borrow(), ["erased foo.bar"]);
+    }
+}